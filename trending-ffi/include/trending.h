@@ -0,0 +1,107 @@
+#ifndef TRENDING_H
+#define TRENDING_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result code returned by every fallible function, details are available
+// through `trending_last_error_message`.
+typedef enum TrendingCode {
+  TRENDING_CODE_OK = 0,
+  TRENDING_CODE_INVALID_ARGUMENT = 1,
+  TRENDING_CODE_UNSUPPORTED_PLATFORM = 2,
+  TRENDING_CODE_HTTP_STATUS = 3,
+  TRENDING_CODE_TIMEOUT = 4,
+  TRENDING_CODE_DECODE = 5,
+  TRENDING_CODE_REQUEST = 6,
+  TRENDING_CODE_OTHER = 7,
+} TrendingCode;
+
+// Opaque handle of a blocking client.
+typedef struct TrendingClient TrendingClient;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a client with default options, free it with `trending_client_free`.
+// Returns NULL if the client could not be created.
+struct TrendingClient *trending_client_new(void);
+
+// Creates a client from JSON options, e.g.
+// `{"headers": {"user-agent": "..."}, "platform_headers": {"douyin": {"cookie": "..."}},
+// "timeout_ms": 5000, "proxy": "...", "retries": 2, "cache_ttl_ms": 60000}`.
+//
+// # Safety
+//
+// `options_json` must be a NUL-terminated string and `out` a valid pointer.
+enum TrendingCode trending_client_new_with_options(const char *options_json,
+                                                   struct TrendingClient **out);
+
+// Frees a client, passing NULL is a no-op.
+//
+// # Safety
+//
+// `client` must come from `trending_client_new*` and must not be used afterwards.
+void trending_client_free(struct TrendingClient *client);
+
+// Fetches trendings of a platform (e.g. `"zhihu"`) and writes them to `out_json`
+// as a JSON string, free it with `trending_string_free`.
+//
+// # Safety
+//
+// `client` must be a live client, `platform` a NUL-terminated string and
+// `out_json` a valid pointer.
+enum TrendingCode trending_trending(const struct TrendingClient *client,
+                                    const char *platform,
+                                    char **out_json);
+
+// Like `trending_trending` with a JSON request, e.g.
+// `{"board": "movie"}` or `{"params": {"language": "rust", "since": "weekly"}}`.
+//
+// # Safety
+//
+// `client` must be a live client, `platform` and `req_json` NUL-terminated
+// strings and `out_json` a valid pointer.
+enum TrendingCode trending_trending_with_req(const struct TrendingClient *client,
+                                             const char *platform,
+                                             const char *req_json,
+                                             char **out_json);
+
+// Searches a platform with a JSON request, e.g.
+// `{"keyword": "...", "page": "First" | {"Other": 2}, "size": 20, "kind": "video"}`
+// with the optional `since`, `until` and `sort` filters, and writes the result
+// to `out_json` as a JSON string, free it with `trending_string_free`. `since`
+// and `until` are applied to the fetched results, undated ones are kept. Filters
+// the platform does not support are listed in the `ignored` field, the `next`
+// field such as `{"Offset": 20}` or `{"Cursor": "..."}` is the `page` of the
+// following request.
+//
+// # Safety
+//
+// `client` must be a live client, `platform` and `req_json` NUL-terminated
+// strings and `out_json` a valid pointer.
+enum TrendingCode trending_search(const struct TrendingClient *client,
+                                  const char *platform,
+                                  const char *req_json,
+                                  char **out_json);
+
+// Frees a string returned by this library, passing NULL is a no-op.
+//
+// # Safety
+//
+// `s` must come from this library and must not be used afterwards.
+void trending_string_free(char *s);
+
+// Message of the last error on the calling thread, or NULL if the last call
+// succeeded. The pointer stays valid until the next call on the same thread.
+const char *trending_last_error_message(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TRENDING_H */
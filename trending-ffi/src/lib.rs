@@ -0,0 +1,439 @@
+use std::{
+  any::Any,
+  cell::RefCell,
+  collections::HashMap,
+  ffi::{CStr, CString, c_char},
+  panic::{AssertUnwindSafe, catch_unwind},
+  ptr,
+  str::FromStr,
+  time::Duration,
+};
+
+use reqwest::{
+  Proxy,
+  header::{HeaderName, HeaderValue},
+};
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
+use trending::{
+  client::{BlockClient, ClientOptions as RClientOptions},
+  common::{PlatformType, SearchReq, TrendingReq},
+  errors::{ReqwestClientSnafu, ReqwestHeaderNameSnafu, ReqwestHeaderValueSnafu, TrendingError},
+};
+
+/// Result code returned by every fallible function, details are available
+/// through `trending_last_error_message`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendingCode {
+  Ok = 0,
+  InvalidArgument = 1,
+  UnsupportedPlatform = 2,
+  HttpStatus = 3,
+  Timeout = 4,
+  Decode = 5,
+  Request = 6,
+  Other = 7,
+}
+
+/// Opaque handle of a blocking client.
+pub struct TrendingClient {
+  client: BlockClient,
+}
+
+#[derive(Deserialize, Debug)]
+struct ClientOptions {
+  #[serde(rename = "headers", default)]
+  headers: HashMap<String, String>,
+
+  #[serde(rename = "platform_headers", default)]
+  platform_headers: HashMap<String, HashMap<String, String>>,
+
+  #[serde(rename = "timeout_ms")]
+  timeout_ms: Option<u64>,
+
+  #[serde(rename = "proxy")]
+  proxy: Option<String>,
+
+  #[serde(rename = "retries")]
+  retries: Option<u32>,
+
+  #[serde(rename = "cache_ttl_ms")]
+  cache_ttl_ms: Option<u64>,
+}
+
+impl TryFrom<ClientOptions> for RClientOptions {
+  type Error = TrendingError;
+
+  fn try_from(value: ClientOptions) -> Result<Self, Self::Error> {
+    let mut options = RClientOptions::new();
+    for (name, value) in value.headers {
+      let (name, value) = parse_header(&name, &value)?;
+      options = options.with_header(name, value);
+    }
+    for (platform, headers) in value.platform_headers {
+      let platform = PlatformType::from(platform.as_str());
+      for (name, value) in headers {
+        let (name, value) = parse_header(&name, &value)?;
+        options = options.with_platform_header(platform.clone(), name, value);
+      }
+    }
+    if let Some(timeout) = value.timeout_ms {
+      options = options.with_timeout(Duration::from_millis(timeout));
+    }
+    if let Some(proxy) = value.proxy {
+      options = options.with_proxy(Proxy::all(proxy).context(ReqwestClientSnafu)?);
+    }
+    if let Some(retries) = value.retries {
+      options = options.with_retries(retries);
+    }
+    if let Some(ttl) = value.cache_ttl_ms {
+      options = options.with_cache_ttl(Duration::from_millis(ttl));
+    }
+    Ok(options)
+  }
+}
+
+fn parse_header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue), TrendingError> {
+  let name = HeaderName::from_str(name).context(ReqwestHeaderNameSnafu { name })?;
+  let value = HeaderValue::from_str(value).context(ReqwestHeaderValueSnafu { value })?;
+  Ok((name, value))
+}
+
+thread_local! {
+  static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+  let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+  LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+fn invalid_argument(message: impl Into<String>) -> TrendingCode {
+  set_last_error(message.into());
+  TrendingCode::InvalidArgument
+}
+
+fn other_error(message: impl Into<String>) -> TrendingCode {
+  set_last_error(message.into());
+  TrendingCode::Other
+}
+
+fn trending_error(err: TrendingError) -> TrendingCode {
+  let code = if matches!(err, TrendingError::UnsupportedPlatform { .. }) {
+    TrendingCode::UnsupportedPlatform
+  } else if matches!(
+    err,
+    TrendingError::UnsupportedBoard { .. } | TrendingError::InvalidParam { .. }
+  ) {
+    TrendingCode::InvalidArgument
+  } else if err.is_timeout() {
+    TrendingCode::Timeout
+  } else if err.status().is_some() {
+    TrendingCode::HttpStatus
+  } else if err.is_decode() {
+    TrendingCode::Decode
+  } else if matches!(err, TrendingError::ReqwestClient { .. }) {
+    TrendingCode::Request
+  } else {
+    TrendingCode::Other
+  };
+  set_last_error(err.detailed_message());
+  code
+}
+
+fn panic_error(payload: Box<dyn Any + Send>) -> TrendingCode {
+  let message = payload
+    .downcast_ref::<&str>()
+    .map(|s| s.to_string())
+    .or_else(|| payload.downcast_ref::<String>().cloned())
+    .unwrap_or_else(|| "unknown panic".to_string());
+  other_error(format!("trending panicked: {}", message))
+}
+
+/// Runs the body of an entry point, a panic must not unwind into the host so
+/// it is reported as `TrendingCode::Other`.
+fn ffi_call(f: impl FnOnce() -> Result<(), TrendingCode>) -> TrendingCode {
+  LAST_ERROR.with(|e| *e.borrow_mut() = None);
+  match catch_unwind(AssertUnwindSafe(f)) {
+    Ok(Ok(())) => TrendingCode::Ok,
+    Ok(Err(code)) => code,
+    Err(payload) => panic_error(payload),
+  }
+}
+
+unsafe fn to_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, TrendingCode> {
+  if ptr.is_null() {
+    return Err(invalid_argument(format!("{} must not be null", name)));
+  }
+  unsafe { CStr::from_ptr(ptr) }
+    .to_str()
+    .map_err(|_| invalid_argument(format!("{} is not valid UTF-8", name)))
+}
+
+unsafe fn to_client<'a>(ptr: *const TrendingClient) -> Result<&'a BlockClient, TrendingCode> {
+  unsafe { ptr.as_ref() }
+    .map(|c| &c.client)
+    .ok_or_else(|| invalid_argument("client must not be null"))
+}
+
+unsafe fn write_json<T: Serialize>(value: &T, out: *mut *mut c_char) -> Result<(), TrendingCode> {
+  let json = serde_json::to_string(value).map_err(|e| other_error(e.to_string()))?;
+  let json = CString::new(json).map_err(|e| other_error(e.to_string()))?;
+  unsafe { *out = json.into_raw() };
+  Ok(())
+}
+
+/// Creates a client with default options, free it with `trending_client_free`.
+/// Returns NULL if the client could not be created.
+#[unsafe(no_mangle)]
+pub extern "C" fn trending_client_new() -> *mut TrendingClient {
+  let mut client = ptr::null_mut();
+  ffi_call(|| {
+    client = Box::into_raw(Box::new(TrendingClient {
+      client: BlockClient::new(),
+    }));
+    Ok(())
+  });
+  client
+}
+
+/// Creates a client from JSON options, e.g.
+/// `{"headers": {"user-agent": "..."}, "platform_headers": {"douyin": {"cookie": "..."}},
+/// "timeout_ms": 5000, "proxy": "...", "retries": 2, "cache_ttl_ms": 60000}`.
+///
+/// # Safety
+///
+/// `options_json` must be a NUL-terminated string and `out` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trending_client_new_with_options(
+  options_json: *const c_char,
+  out: *mut *mut TrendingClient,
+) -> TrendingCode {
+  ffi_call(|| {
+    if out.is_null() {
+      return Err(invalid_argument("out must not be null"));
+    }
+    let options = unsafe { to_str(options_json, "options_json") }?;
+    let options: ClientOptions =
+      serde_json::from_str(options).map_err(|e| invalid_argument(e.to_string()))?;
+    let options = RClientOptions::try_from(options).map_err(trending_error)?;
+    let client = BlockClient::new_with_options(options).map_err(trending_error)?;
+    unsafe { *out = Box::into_raw(Box::new(TrendingClient { client })) };
+    Ok(())
+  })
+}
+
+/// Frees a client, passing NULL is a no-op.
+///
+/// # Safety
+///
+/// `client` must come from `trending_client_new*` and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trending_client_free(client: *mut TrendingClient) {
+  if !client.is_null() {
+    // a panicking drop must not unwind into the host either
+    let _ = catch_unwind(AssertUnwindSafe(|| drop(unsafe { Box::from_raw(client) })));
+  }
+}
+
+/// Fetches trendings of a platform (e.g. `"zhihu"`) and writes them to `out_json`
+/// as a JSON string, free it with `trending_string_free`.
+///
+/// # Safety
+///
+/// `client` must be a live client, `platform` a NUL-terminated string and
+/// `out_json` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trending_trending(
+  client: *const TrendingClient,
+  platform: *const c_char,
+  out_json: *mut *mut c_char,
+) -> TrendingCode {
+  ffi_call(|| {
+    if out_json.is_null() {
+      return Err(invalid_argument("out_json must not be null"));
+    }
+    let client = unsafe { to_client(client) }?;
+    let platform = PlatformType::from(unsafe { to_str(platform, "platform") }?);
+    let res = client.trending(&platform).map_err(trending_error)?;
+    unsafe { write_json(&res, out_json) }
+  })
+}
+
+/// Like `trending_trending` with a JSON request, e.g.
+/// `{"board": "movie"}` or `{"params": {"language": "rust", "since": "weekly"}}`.
+///
+/// # Safety
+///
+/// `client` must be a live client, `platform` and `req_json` NUL-terminated
+/// strings and `out_json` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trending_trending_with_req(
+  client: *const TrendingClient,
+  platform: *const c_char,
+  req_json: *const c_char,
+  out_json: *mut *mut c_char,
+) -> TrendingCode {
+  ffi_call(|| {
+    if out_json.is_null() {
+      return Err(invalid_argument("out_json must not be null"));
+    }
+    let client = unsafe { to_client(client) }?;
+    let platform = PlatformType::from(unsafe { to_str(platform, "platform") }?);
+    let req = unsafe { to_str(req_json, "req_json") }?;
+    let req: TrendingReq =
+      serde_json::from_str(req).map_err(|e| invalid_argument(e.to_string()))?;
+    let res = client
+      .trending_with_req(&platform, &req)
+      .map_err(trending_error)?;
+    unsafe { write_json(&res, out_json) }
+  })
+}
+
+/// Searches a platform with a JSON request, e.g.
+/// `{"keyword": "...", "page": "First" | {"Other": 2}, "size": 20, "kind": "video"}`
+/// with the optional `since`, `until` and `sort` filters, and writes the result
+/// to `out_json` as a JSON string, free it with `trending_string_free`. `since`
+/// and `until` are applied to the fetched results, undated ones are kept. Filters
+/// the platform does not support are listed in the `ignored` field, the `next`
+/// field such as `{"Offset": 20}` or `{"Cursor": "..."}` is the `page` of the
+/// following request.
+///
+/// # Safety
+///
+/// `client` must be a live client, `platform` and `req_json` NUL-terminated
+/// strings and `out_json` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trending_search(
+  client: *const TrendingClient,
+  platform: *const c_char,
+  req_json: *const c_char,
+  out_json: *mut *mut c_char,
+) -> TrendingCode {
+  ffi_call(|| {
+    if out_json.is_null() {
+      return Err(invalid_argument("out_json must not be null"));
+    }
+    let client = unsafe { to_client(client) }?;
+    let platform = PlatformType::from(unsafe { to_str(platform, "platform") }?);
+    let req = unsafe { to_str(req_json, "req_json") }?;
+    let req: SearchReq = serde_json::from_str(req).map_err(|e| invalid_argument(e.to_string()))?;
+    let res = client.search(&platform, &req).map_err(trending_error)?;
+    unsafe { write_json(&res, out_json) }
+  })
+}
+
+/// Frees a string returned by this library, passing NULL is a no-op.
+///
+/// # Safety
+///
+/// `s` must come from this library and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn trending_string_free(s: *mut c_char) {
+  if !s.is_null() {
+    // a panicking drop must not unwind into the host either
+    let _ = catch_unwind(AssertUnwindSafe(|| drop(unsafe { CString::from_raw(s) })));
+  }
+}
+
+/// Message of the last error on the calling thread, or NULL if the last call
+/// succeeded. The pointer stays valid until the next call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn trending_last_error_message() -> *const c_char {
+  catch_unwind(|| LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr())))
+    .unwrap_or(ptr::null())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn last_error() -> Option<String> {
+    let message = trending_last_error_message();
+    (!message.is_null()).then(|| {
+      unsafe { CStr::from_ptr(message) }
+        .to_string_lossy()
+        .into_owned()
+    })
+  }
+
+  #[test]
+  fn client_new_and_free() {
+    let client = trending_client_new();
+    assert!(!client.is_null());
+    unsafe { trending_client_free(client) };
+    unsafe { trending_client_free(ptr::null_mut()) };
+
+    let options = c"{\"timeout_ms\": 5000, \"retries\": 2, \"cache_ttl_ms\": 60000}";
+    let mut client = ptr::null_mut();
+    let code = unsafe { trending_client_new_with_options(options.as_ptr(), &mut client) };
+    assert_eq!(code, TrendingCode::Ok);
+    assert!(!client.is_null());
+    assert_eq!(last_error(), None);
+    unsafe { trending_client_free(client) };
+  }
+
+  #[test]
+  fn client_rejects_invalid_options() {
+    let mut client = ptr::null_mut();
+    let code = unsafe { trending_client_new_with_options(c"{".as_ptr(), &mut client) };
+    assert_eq!(code, TrendingCode::InvalidArgument);
+    assert!(client.is_null());
+    assert!(last_error().is_some());
+
+    let options = c"{\"headers\": {\"bad header\": \"x\"}}";
+    let code = unsafe { trending_client_new_with_options(options.as_ptr(), &mut client) };
+    assert_eq!(code, TrendingCode::Other);
+    assert!(last_error().unwrap().contains("bad header"));
+
+    let code = unsafe { trending_client_new_with_options(ptr::null(), &mut client) };
+    assert_eq!(code, TrendingCode::InvalidArgument);
+    assert_eq!(
+      last_error().as_deref(),
+      Some("options_json must not be null")
+    );
+  }
+
+  #[test]
+  fn trending_reports_unsupported_platform() {
+    let client = trending_client_new();
+    let mut out = ptr::null_mut();
+    let code = unsafe { trending_trending(client, c"nope".as_ptr(), &mut out) };
+    assert_eq!(code, TrendingCode::UnsupportedPlatform);
+    assert!(out.is_null());
+    assert!(last_error().unwrap().contains("nope"));
+
+    let req = c"{\"keyword\": \"rust\"}";
+    let code = unsafe { trending_search(client, c"nope".as_ptr(), req.as_ptr(), &mut out) };
+    assert_eq!(code, TrendingCode::UnsupportedPlatform);
+    unsafe { trending_client_free(client) };
+  }
+
+  #[test]
+  fn last_error_is_cleared_by_a_successful_call() {
+    let mut out = ptr::null_mut();
+    let code = unsafe { trending_trending(ptr::null(), c"zhihu".as_ptr(), &mut out) };
+    assert_eq!(code, TrendingCode::InvalidArgument);
+    assert_eq!(last_error().as_deref(), Some("client must not be null"));
+
+    let client = trending_client_new();
+    assert!(!client.is_null());
+    assert_eq!(last_error(), None);
+    unsafe { trending_client_free(client) };
+  }
+
+  #[test]
+  fn string_free_accepts_null() {
+    unsafe { trending_string_free(ptr::null_mut()) };
+    let s = CString::new("trending").unwrap().into_raw();
+    unsafe { trending_string_free(s) };
+  }
+
+  #[test]
+  fn panic_is_reported_as_other() {
+    let code = ffi_call(|| panic!("boom"));
+    assert_eq!(code, TrendingCode::Other);
+    assert_eq!(last_error().as_deref(), Some("trending panicked: boom"));
+  }
+}
@@ -1,49 +1,516 @@
-use pyo3::prelude::*;
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+
+create_exception!(
+  trending,
+  TrendingError,
+  PyException,
+  "Base class of all errors raised by trending."
+);
+create_exception!(
+  trending,
+  HttpStatusError,
+  TrendingError,
+  "The platform responded with a non-success http status."
+);
+create_exception!(
+  trending,
+  RequestTimeoutError,
+  TrendingError,
+  "The request to the platform timed out."
+);
+create_exception!(
+  trending,
+  DecodeError,
+  TrendingError,
+  "The platform response could not be decoded."
+);
 
 #[pymodule]
 mod trending {
+  #[pymodule_export]
+  use super::{DecodeError, HttpStatusError, RequestTimeoutError, TrendingError};
 
   use std::{
+    collections::{BTreeMap, HashMap},
     fmt::{Display, Formatter},
     str::FromStr,
     time::Duration,
   };
 
-  use pyo3::{exceptions::PyOSError, prelude::*};
+  use pyo3::{
+    marker::Ungil,
+    prelude::*,
+    types::{PyDict, PyTuple},
+  };
   use reqwest::{
     Proxy,
     header::{HeaderName, HeaderValue},
   };
+  use serde::{Deserialize, Serialize};
   use snafu::ResultExt;
   use trending::{
     client::{BlockClient as RBlockClient, ClientOptions as RClientOptions},
     common::{
-      MediaData as RMediaData, PageParam, SearchReq as RSearchReq, SearchRes as RSearchRes,
-      SearchesRes as RSearchesRes, TrendingRes as RTrendingRes, TrendingsRes as RTrendingsRes,
+      MediaData as RMediaData, MediaType as RMediaType, PageParam as RPageParam,
+      PlatformType as RPlatformType, SearchFilter as RSearchFilter, SearchReq as RSearchReq,
+      SearchRes as RSearchRes, SearchSort as RSearchSort, SearchType as RSearchType,
+      SearchesRes as RSearchesRes, TopicRes as RTopicRes, TrendingReq as RTrendingReq,
+      TrendingRes as RTrendingRes, TrendingsRes as RTrendingsRes,
     },
     errors::{
-      ReqwestClientSnafu, ReqwestHeaderNameSnafu, ReqwestHeaderValueSnafu,
-      TrendingError as RTrendingError,
+      DeserializeJsonSnafu, ReqwestClientSnafu, ReqwestHeaderNameSnafu, ReqwestHeaderValueSnafu,
+      SerializeJsonSnafu, TrendingError as RTrendingError,
     },
   };
 
-  #[pyclass]
   #[derive(Debug)]
-  pub struct TrendingError(RTrendingError);
+  pub struct Error {
+    source: RTrendingError,
+    platform: Option<RPlatformType>,
+  }
+
+  impl Error {
+    fn into_pyerr(self, py: Python<'_>) -> PyResult<PyErr> {
+      let message = self.source.detailed_message();
+      let err = if self.source.is_timeout() {
+        RequestTimeoutError::new_err(message)
+      } else if self.source.status().is_some() {
+        HttpStatusError::new_err(message)
+      } else if self.source.is_decode() {
+        DecodeError::new_err(message)
+      } else {
+        TrendingError::new_err(message)
+      };
+      let value = err.value(py);
+      value.setattr("platform", self.platform.as_ref().map(|p| p.to_str()))?;
+      value.setattr("endpoint", self.source.endpoint())?;
+      value.setattr("status", self.source.status())?;
+      Ok(err)
+    }
+  }
+
+  impl From<RTrendingError> for Error {
+    fn from(source: RTrendingError) -> Self {
+      Self {
+        source,
+        platform: None,
+      }
+    }
+  }
+
+  impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+      Python::attach(|py| err.into_pyerr(py).unwrap_or_else(|e| e))
+    }
+  }
+
+  pub type Result<T> = std::result::Result<T, Error>;
+
+  fn detach<T, F>(py: Python<'_>, platform: RPlatformType, f: F) -> Result<T>
+  where
+    trending::errors::Result<T>: Ungil,
+    F: Ungil + FnOnce() -> trending::errors::Result<T>,
+  {
+    py.detach(f).map_err(|source| Error {
+      source,
+      platform: Some(platform),
+    })
+  }
+
+  fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    Ok(serde_json::to_string(value).context(SerializeJsonSnafu)?)
+  }
+
+  fn from_json<T: for<'de> Deserialize<'de>>(json: &str) -> Result<T> {
+    Ok(serde_json::from_str(json).context(DeserializeJsonSnafu)?)
+  }
+
+  fn to_dict<'py, T: Serialize>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> {
+    let json = to_json(value)?;
+    py.import("json")?.call_method1("loads", (json,))
+  }
+
+  fn repr(slf: &Bound<'_, PyAny>, fields: &[&str]) -> PyResult<String> {
+    let mut attrs = Vec::with_capacity(fields.len());
+    for field in fields {
+      attrs.push(format!("{}={}", field, slf.getattr(*field)?.repr()?));
+    }
+    Ok(format!("{}({})", slf.get_type().name()?, attrs.join(", ")))
+  }
+
+  fn reduce<'py>(slf: &Bound<'py, PyAny>, json: String) -> PyResult<Bound<'py, PyTuple>> {
+    let from_json = slf.get_type().getattr("from_json")?;
+    PyTuple::new(
+      slf.py(),
+      [from_json, PyTuple::new(slf.py(), [json])?.into_any()],
+    )
+  }
+
+  macro_rules! data_class_methods {
+    ($name:ident, [$($field:literal),*] $(, $($extra:tt)*)?) => {
+      #[pymethods]
+      impl $name {
+        $($($extra)*)?
+
+        pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+          to_dict(py, self)
+        }
+
+        pub fn to_json(&self) -> Result<String> {
+          to_json(self)
+        }
+
+        #[staticmethod]
+        pub fn from_json(json: &str) -> Result<Self> {
+          from_json(json)
+        }
+
+        fn __repr__(slf: &Bound<'_, Self>) -> PyResult<String> {
+          repr(slf.as_any(), &[$($field),*])
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyTuple>> {
+          reduce(slf.as_any(), to_json(&*slf.borrow())?)
+        }
+      }
+    };
+  }
+
+  #[pyclass(eq, hash, frozen, str)]
+  #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+  #[serde(from = "RPlatformType", into = "RPlatformType")]
+  pub enum PlatformType {
+    Zhihu,
+    Weibo,
+    Toutiao,
+    Tencent,
+    Tieba,
+    Netease,
+    Hupu,
+    Bilibili,
+    Douyin,
+    Baidu,
+    Douban,
+    Kuaishou,
+    Kr36,
+    Huxiu,
+    Ithome,
+    Sspai,
+    Juejin,
+    V2ex,
+    Csdn,
+    HackerNews,
+    Reddit,
+    Github,
+    Thepaper,
+    Ifeng,
+    Other,
+  }
+
+  impl From<RPlatformType> for PlatformType {
+    fn from(value: RPlatformType) -> Self {
+      match value {
+        RPlatformType::Zhihu => PlatformType::Zhihu,
+        RPlatformType::Weibo => PlatformType::Weibo,
+        RPlatformType::Toutiao => PlatformType::Toutiao,
+        RPlatformType::Tencent => PlatformType::Tencent,
+        RPlatformType::Tieba => PlatformType::Tieba,
+        RPlatformType::Netease => PlatformType::Netease,
+        RPlatformType::Hupu => PlatformType::Hupu,
+        RPlatformType::Bilibili => PlatformType::Bilibili,
+        RPlatformType::Douyin => PlatformType::Douyin,
+        RPlatformType::Baidu => PlatformType::Baidu,
+        RPlatformType::Douban => PlatformType::Douban,
+        RPlatformType::Kuaishou => PlatformType::Kuaishou,
+        RPlatformType::Kr36 => PlatformType::Kr36,
+        RPlatformType::Huxiu => PlatformType::Huxiu,
+        RPlatformType::Ithome => PlatformType::Ithome,
+        RPlatformType::Sspai => PlatformType::Sspai,
+        RPlatformType::Juejin => PlatformType::Juejin,
+        RPlatformType::V2ex => PlatformType::V2ex,
+        RPlatformType::Csdn => PlatformType::Csdn,
+        RPlatformType::HackerNews => PlatformType::HackerNews,
+        RPlatformType::Reddit => PlatformType::Reddit,
+        RPlatformType::Github => PlatformType::Github,
+        RPlatformType::Thepaper => PlatformType::Thepaper,
+        RPlatformType::Ifeng => PlatformType::Ifeng,
+        RPlatformType::Other(_) => PlatformType::Other,
+      }
+    }
+  }
+
+  impl From<PlatformType> for RPlatformType {
+    fn from(value: PlatformType) -> Self {
+      match value {
+        PlatformType::Zhihu => RPlatformType::Zhihu,
+        PlatformType::Weibo => RPlatformType::Weibo,
+        PlatformType::Toutiao => RPlatformType::Toutiao,
+        PlatformType::Tencent => RPlatformType::Tencent,
+        PlatformType::Tieba => RPlatformType::Tieba,
+        PlatformType::Netease => RPlatformType::Netease,
+        PlatformType::Hupu => RPlatformType::Hupu,
+        PlatformType::Bilibili => RPlatformType::Bilibili,
+        PlatformType::Douyin => RPlatformType::Douyin,
+        PlatformType::Baidu => RPlatformType::Baidu,
+        PlatformType::Douban => RPlatformType::Douban,
+        PlatformType::Kuaishou => RPlatformType::Kuaishou,
+        PlatformType::Kr36 => RPlatformType::Kr36,
+        PlatformType::Huxiu => RPlatformType::Huxiu,
+        PlatformType::Ithome => RPlatformType::Ithome,
+        PlatformType::Sspai => RPlatformType::Sspai,
+        PlatformType::Juejin => RPlatformType::Juejin,
+        PlatformType::V2ex => RPlatformType::V2ex,
+        PlatformType::Csdn => RPlatformType::Csdn,
+        PlatformType::HackerNews => RPlatformType::HackerNews,
+        PlatformType::Reddit => RPlatformType::Reddit,
+        PlatformType::Github => RPlatformType::Github,
+        PlatformType::Thepaper => RPlatformType::Thepaper,
+        PlatformType::Ifeng => RPlatformType::Ifeng,
+        PlatformType::Other => RPlatformType::Other("other".to_string()),
+      }
+    }
+  }
+
+  impl Display for PlatformType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{}", RPlatformType::from(self.clone()))
+    }
+  }
+
+  #[pyclass(eq, hash, frozen, str)]
+  #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+  #[serde(from = "RMediaType", into = "RMediaType")]
+  pub enum MediaType {
+    Video,
+    Audio,
+    Image,
+    Other,
+  }
+
+  impl From<RMediaType> for MediaType {
+    fn from(value: RMediaType) -> Self {
+      match value {
+        RMediaType::Video => MediaType::Video,
+        RMediaType::Audio => MediaType::Audio,
+        RMediaType::Image => MediaType::Image,
+        RMediaType::Other(_) => MediaType::Other,
+      }
+    }
+  }
+
+  impl From<MediaType> for RMediaType {
+    fn from(value: MediaType) -> Self {
+      match value {
+        MediaType::Video => RMediaType::Video,
+        MediaType::Audio => RMediaType::Audio,
+        MediaType::Image => RMediaType::Image,
+        MediaType::Other => RMediaType::Other("other".to_string()),
+      }
+    }
+  }
+
+  impl Display for MediaType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{}", RMediaType::from(self.clone()).as_str())
+    }
+  }
+
+  #[pyclass(eq, hash, frozen, str)]
+  #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+  #[serde(from = "RSearchType", into = "RSearchType")]
+  pub enum SearchType {
+    News,
+    Video,
+    Account,
+  }
+
+  impl From<RSearchType> for SearchType {
+    fn from(value: RSearchType) -> Self {
+      match value {
+        RSearchType::News => SearchType::News,
+        RSearchType::Video => SearchType::Video,
+        RSearchType::Account => SearchType::Account,
+      }
+    }
+  }
+
+  impl From<SearchType> for RSearchType {
+    fn from(value: SearchType) -> Self {
+      match value {
+        SearchType::News => RSearchType::News,
+        SearchType::Video => RSearchType::Video,
+        SearchType::Account => RSearchType::Account,
+      }
+    }
+  }
+
+  impl Display for SearchType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{}", RSearchType::from(self.clone()).as_str())
+    }
+  }
+
+  #[pyclass(eq, hash, frozen, str)]
+  #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+  #[serde(from = "RSearchSort", into = "RSearchSort")]
+  pub enum SearchSort {
+    Relevance,
+    Newest,
+    Hottest,
+  }
+
+  impl From<RSearchSort> for SearchSort {
+    fn from(value: RSearchSort) -> Self {
+      match value {
+        RSearchSort::Relevance => SearchSort::Relevance,
+        RSearchSort::Newest => SearchSort::Newest,
+        RSearchSort::Hottest => SearchSort::Hottest,
+      }
+    }
+  }
+
+  impl From<SearchSort> for RSearchSort {
+    fn from(value: SearchSort) -> Self {
+      match value {
+        SearchSort::Relevance => RSearchSort::Relevance,
+        SearchSort::Newest => RSearchSort::Newest,
+        SearchSort::Hottest => RSearchSort::Hottest,
+      }
+    }
+  }
+
+  impl Display for SearchSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{}", RSearchSort::from(self.clone()).as_str())
+    }
+  }
+
+  #[pyclass(eq, hash, frozen, str)]
+  #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+  #[serde(from = "RSearchFilter", into = "RSearchFilter")]
+  pub enum SearchFilter {
+    Since,
+    Until,
+    Sort,
+    Kind,
+    Size,
+  }
+
+  impl From<RSearchFilter> for SearchFilter {
+    fn from(value: RSearchFilter) -> Self {
+      match value {
+        RSearchFilter::Since => SearchFilter::Since,
+        RSearchFilter::Until => SearchFilter::Until,
+        RSearchFilter::Sort => SearchFilter::Sort,
+        RSearchFilter::Kind => SearchFilter::Kind,
+        RSearchFilter::Size => SearchFilter::Size,
+      }
+    }
+  }
 
-  impl From<RTrendingError> for TrendingError {
-    fn from(err: RTrendingError) -> Self {
-      Self(err)
+  impl From<SearchFilter> for RSearchFilter {
+    fn from(value: SearchFilter) -> Self {
+      match value {
+        SearchFilter::Since => RSearchFilter::Since,
+        SearchFilter::Until => RSearchFilter::Until,
+        SearchFilter::Sort => RSearchFilter::Sort,
+        SearchFilter::Kind => RSearchFilter::Kind,
+        SearchFilter::Size => RSearchFilter::Size,
+      }
+    }
+  }
+
+  impl Display for SearchFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{}", RSearchFilter::from(self.clone()).as_str())
+    }
+  }
+
+  #[pyclass(eq, frozen, str)]
+  #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+  #[serde(from = "RPageParam", into = "RPageParam")]
+  pub enum PageParam {
+    First(),
+    Other(u32),
+    Offset(u32),
+    Cursor(String),
+  }
+
+  impl From<RPageParam> for PageParam {
+    fn from(value: RPageParam) -> Self {
+      match value {
+        RPageParam::First => PageParam::First(),
+        RPageParam::Other(page) => PageParam::Other(page),
+        RPageParam::Offset(offset) => PageParam::Offset(offset),
+        RPageParam::Cursor(cursor) => PageParam::Cursor(cursor),
+      }
+    }
+  }
+
+  impl From<PageParam> for RPageParam {
+    fn from(value: PageParam) -> Self {
+      match value {
+        PageParam::First() => RPageParam::First,
+        PageParam::Other(page) => RPageParam::Other(page),
+        PageParam::Offset(offset) => RPageParam::Offset(offset),
+        PageParam::Cursor(cursor) => RPageParam::Cursor(cursor),
+      }
     }
   }
 
-  impl From<TrendingError> for PyErr {
-    fn from(err: TrendingError) -> Self {
-      PyOSError::new_err(err.0.to_string())
+  impl Display for PageParam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      match self {
+        PageParam::First() => write!(f, "PageParam.First()"),
+        PageParam::Other(page) => write!(f, "PageParam.Other({})", page),
+        PageParam::Offset(offset) => write!(f, "PageParam.Offset({})", offset),
+        PageParam::Cursor(cursor) => write!(f, "PageParam.Cursor({:?})", cursor),
+      }
     }
   }
 
-  pub type Result<T> = std::result::Result<T, TrendingError>;
+  #[pymethods]
+  impl SearchFilter {
+    /// Whether the client applies the filter to the fetched results, which is
+    /// the case of `since` and `until`, undated results are kept.
+    pub fn is_client_side(&self) -> bool {
+      RSearchFilter::from(self.clone()).is_client_side()
+    }
+  }
+
+  #[pymethods]
+  impl PlatformType {
+    /// Filters of `SearchReq` the search of this platform honors natively.
+    pub fn search_filters(&self) -> Vec<SearchFilter> {
+      RPlatformType::from(self.clone())
+        .search_filters()
+        .iter()
+        .map(|f| (*f).into())
+        .collect()
+    }
+  }
+
+  #[pymethods]
+  impl PageParam {
+    fn __repr__(&self) -> String {
+      self.to_string()
+    }
+  }
+
+  #[derive(FromPyObject)]
+  pub enum PageArg {
+    Param(PageParam),
+    Number(u32),
+  }
+
+  impl From<PageArg> for PageParam {
+    fn from(value: PageArg) -> Self {
+      match value {
+        PageArg::Param(page) => page,
+        PageArg::Number(page) => PageParam::Other(page),
+      }
+    }
+  }
 
   #[pyclass(str)]
   #[derive(Debug, Clone)]
@@ -60,23 +527,98 @@ mod trending {
   #[pymethods]
   impl ClientOptions {
     #[new]
-    pub fn new() -> Self {
-      Self {
+    #[pyo3(signature = (headers = None, timeout = None, proxy = None, retries = None, cache_ttl = None, platform_headers = None))]
+    pub fn new(
+      headers: Option<HashMap<String, String>>,
+      timeout: Option<Duration>,
+      proxy: Option<&str>,
+      retries: Option<u32>,
+      cache_ttl: Option<Duration>,
+      platform_headers: Option<HashMap<PlatformType, HashMap<String, String>>>,
+    ) -> Result<Self> {
+      let mut options = Self {
         options: RClientOptions::new(),
+      };
+      if let Some(headers) = headers {
+        options.with_headers(headers)?;
       }
+      for (platform, headers) in platform_headers.unwrap_or_default() {
+        options.with_platform_headers(platform, headers)?;
+      }
+      if let Some(proxy) = proxy {
+        options.with_proxy(proxy)?;
+      }
+      options.options.timeout = timeout;
+      options.options.retries = retries;
+      options.options.cache_ttl = cache_ttl;
+      Ok(options)
+    }
+
+    #[getter]
+    pub fn headers(&self) -> HashMap<String, String> {
+      self
+        .options
+        .headers
+        .iter()
+        .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+        .collect()
+    }
+
+    #[getter]
+    pub fn timeout(&self) -> Option<Duration> {
+      self.options.timeout
+    }
+
+    #[getter]
+    pub fn retries(&self) -> Option<u32> {
+      self.options.retries
+    }
+
+    #[getter]
+    pub fn cache_ttl(&self) -> Option<Duration> {
+      self.options.cache_ttl
+    }
+
+    pub fn with_headers(&mut self, headers: HashMap<String, String>) -> Result<()> {
+      for (name, value) in headers {
+        self.with_header(&name, &value)?;
+      }
+      Ok(())
     }
 
     pub fn with_header(&mut self, name: &str, value: &str) -> Result<()> {
-      let name = HeaderName::from_str(name).context(ReqwestHeaderNameSnafu {
-        name: name.to_string(),
-      })?;
-      let value = HeaderValue::from_str(value).context(ReqwestHeaderValueSnafu {
-        value: value.to_string(),
-      })?;
+      let (name, value) = parse_header(name, value)?;
       self.options.headers.insert(name, value);
       Ok(())
     }
 
+    pub fn with_platform_headers(
+      &mut self,
+      platform: PlatformType,
+      headers: HashMap<String, String>,
+    ) -> Result<()> {
+      for (name, value) in headers {
+        self.with_platform_header(platform.clone(), &name, &value)?;
+      }
+      Ok(())
+    }
+
+    pub fn with_platform_header(
+      &mut self,
+      platform: PlatformType,
+      name: &str,
+      value: &str,
+    ) -> Result<()> {
+      let (name, value) = parse_header(name, value)?;
+      self
+        .options
+        .platform_headers
+        .entry(platform.into())
+        .or_default()
+        .insert(name, value);
+      Ok(())
+    }
+
     pub fn with_proxy(&mut self, proxy: &str) -> Result<()> {
       let proxy = Proxy::all(proxy).context(ReqwestClientSnafu)?;
       self.options.proxy = Some(proxy);
@@ -87,18 +629,36 @@ mod trending {
       self.options.timeout = Some(timeout);
     }
 
+    pub fn with_retries(&mut self, retries: u32) {
+      self.options.retries = Some(retries);
+    }
+
+    pub fn with_cache_ttl(&mut self, ttl: Duration) {
+      self.options.cache_ttl = Some(ttl);
+    }
+
     pub fn debug_print(&self) {
       println!("{:?}", self);
     }
   }
 
+  fn parse_header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue)> {
+    let name = HeaderName::from_str(name).context(ReqwestHeaderNameSnafu {
+      name: name.to_string(),
+    })?;
+    let value = HeaderValue::from_str(value).context(ReqwestHeaderValueSnafu {
+      value: value.to_string(),
+    })?;
+    Ok((name, value))
+  }
+
   #[pyclass]
   struct BlockClient {
     client: RBlockClient,
   }
 
-  #[pyclass(str)]
-  #[derive(Debug, Clone)]
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
   pub struct TrendingRes {
     #[pyo3(get, set)]
     title: String,
@@ -108,6 +668,42 @@ mod trending {
 
     #[pyo3(get, set)]
     trend: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    medias: Option<Vec<MediaData>>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    label: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    desc: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    author: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    time: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    reads: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    comments: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    rank: Option<u32>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    language: Option<String>,
   }
 
   impl From<RTrendingRes> for TrendingRes {
@@ -116,6 +712,17 @@ mod trending {
         title: value.title,
         url: value.url,
         trend: value.trend,
+        medias: value
+          .medias
+          .map(|s| s.into_iter().map(|m| m.into()).collect()),
+        label: value.label,
+        desc: value.desc,
+        author: value.author,
+        time: value.time,
+        reads: value.reads,
+        comments: value.comments,
+        rank: value.rank,
+        language: value.language,
       }
     }
   }
@@ -126,13 +733,22 @@ mod trending {
     }
   }
 
-  #[pyclass(str)]
-  #[derive(Debug, Clone)]
+  data_class_methods!(
+    TrendingRes,
+    [
+      "title", "url", "trend", "medias", "label", "desc", "author", "time", "reads", "comments",
+      "rank", "language"
+    ]
+  );
+
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
   pub struct TrendingsRes {
     #[pyo3(get, set)]
-    platform: String,
+    platform: PlatformType,
 
     #[pyo3(get, set)]
+    #[serde(rename = "trendings", default)]
     result: Vec<TrendingRes>,
   }
 
@@ -140,7 +756,7 @@ mod trending {
     fn from(value: RTrendingsRes) -> Self {
       let result = value.result.into_iter().map(|r| r.into()).collect();
       Self {
-        platform: value.platform.to_str().to_string(),
+        platform: value.platform.into(),
         result,
       }
     }
@@ -152,31 +768,166 @@ mod trending {
     }
   }
 
-  #[pyclass(str)]
-  #[derive(Debug, Clone)]
+  data_class_methods!(TrendingsRes, ["platform", "result"]);
+
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+  pub struct TopicRes {
+    #[pyo3(get, set)]
+    platform: PlatformType,
+
+    #[pyo3(get, set)]
+    title: String,
+
+    #[pyo3(get, set)]
+    url: String,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    desc: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    discussions: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    medias: Option<Vec<MediaData>>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    posts: Vec<TrendingRes>,
+  }
+
+  impl From<RTopicRes> for TopicRes {
+    fn from(value: RTopicRes) -> Self {
+      Self {
+        platform: value.platform.into(),
+        title: value.title,
+        url: value.url,
+        desc: value.desc,
+        discussions: value.discussions,
+        medias: value
+          .medias
+          .map(|s| s.into_iter().map(|m| m.into()).collect()),
+        posts: value.posts.into_iter().map(|r| r.into()).collect(),
+      }
+    }
+  }
+
+  impl Display for TopicRes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{:?}", self)
+    }
+  }
+
+  data_class_methods!(
+    TopicRes,
+    [
+      "platform",
+      "title",
+      "url",
+      "desc",
+      "discussions",
+      "medias",
+      "posts"
+    ]
+  );
+
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+  pub struct TrendingReq {
+    #[pyo3(get, set)]
+    #[serde(default)]
+    board: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    params: BTreeMap<String, String>,
+  }
+
+  data_class_methods!(
+    TrendingReq,
+    ["board", "params"],
+    #[new]
+    #[pyo3(signature = (board = None, params = None))]
+    pub fn new(board: Option<String>, params: Option<BTreeMap<String, String>>) -> Self {
+      Self {
+        board,
+        params: params.unwrap_or_default(),
+      }
+    }
+  );
+
+  impl Display for TrendingReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{:?}", self)
+    }
+  }
+
+  impl From<TrendingReq> for RTrendingReq {
+    fn from(value: TrendingReq) -> Self {
+      Self {
+        board: value.board,
+        params: value.params,
+      }
+    }
+  }
+
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
   pub struct SearchReq {
     #[pyo3(get, set)]
     keyword: String,
 
     #[pyo3(get, set)]
-    page: Option<u32>,
+    page: Option<PageParam>,
 
     #[pyo3(get, set)]
     size: Option<u32>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    kind: Option<SearchType>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    since: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    until: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    sort: Option<SearchSort>,
   }
 
-  #[pymethods]
-  impl SearchReq {
+  data_class_methods!(
+    SearchReq,
+    ["keyword", "page", "size", "kind", "since", "until", "sort"],
     #[new]
-    #[pyo3(signature = (keyword, page = None, size = None))]
-    pub fn new(keyword: &str, page: Option<u32>, size: Option<u32>) -> Self {
+    #[pyo3(signature = (keyword, page = None, size = None, kind = None, since = None, until = None, sort = None))]
+    pub fn new(
+      keyword: &str,
+      page: Option<PageArg>,
+      size: Option<u32>,
+      kind: Option<SearchType>,
+      since: Option<u64>,
+      until: Option<u64>,
+      sort: Option<SearchSort>,
+    ) -> Self {
       Self {
         keyword: keyword.to_string(),
-        page,
+        page: page.map(PageParam::from),
         size,
+        kind,
+        since,
+        until,
+        sort,
       }
     }
-  }
+  );
 
   impl Display for SearchReq {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -188,22 +939,27 @@ mod trending {
     fn from(value: SearchReq) -> Self {
       Self {
         keyword: value.keyword,
-        page: value.page.map(|p| PageParam::Other(p)),
+        page: value.page.map(RPageParam::from),
         size: value.size,
+        kind: value.kind.map(RSearchType::from),
+        since: value.since,
+        until: value.until,
+        sort: value.sort.map(RSearchSort::from),
       }
     }
   }
 
-  #[pyclass(str)]
-  #[derive(Debug, Clone)]
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
   pub struct MediaData {
     #[pyo3(get, set)]
     url: String,
 
     #[pyo3(get, set)]
-    kind: String,
+    kind: MediaType,
 
     #[pyo3(get, set)]
+    #[serde(default)]
     desc: Option<String>,
   }
 
@@ -213,18 +969,20 @@ mod trending {
     }
   }
 
+  data_class_methods!(MediaData, ["url", "kind", "desc"]);
+
   impl From<RMediaData> for MediaData {
     fn from(value: RMediaData) -> Self {
       Self {
         url: value.url,
-        kind: value.kind.as_str().to_string(),
+        kind: value.kind.into(),
         desc: value.desc,
       }
     }
   }
 
-  #[pyclass(str)]
-  #[derive(Debug, Clone)]
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
   pub struct SearchRes {
     #[pyo3(get, set)]
     title: String,
@@ -236,7 +994,32 @@ mod trending {
     time: Option<u64>,
 
     #[pyo3(get, set)]
+    #[serde(default)]
     medias: Option<Vec<MediaData>>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    desc: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    author: Option<String>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    likes: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    comments: Option<u64>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    kind: Option<SearchType>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    category: Option<String>,
   }
 
   impl Display for SearchRes {
@@ -245,6 +1028,13 @@ mod trending {
     }
   }
 
+  data_class_methods!(
+    SearchRes,
+    [
+      "title", "url", "time", "medias", "desc", "author", "likes", "comments", "kind", "category"
+    ]
+  );
+
   impl From<RSearchRes> for SearchRes {
     fn from(value: RSearchRes) -> Self {
       Self {
@@ -254,18 +1044,33 @@ mod trending {
         medias: value
           .medias
           .map(|s| s.into_iter().map(|m| m.into()).collect()),
+        desc: value.desc,
+        author: value.author,
+        likes: value.likes,
+        comments: value.comments,
+        kind: value.kind.map(SearchType::from),
+        category: value.category,
       }
     }
   }
 
-  #[pyclass(str)]
-  #[derive(Debug, Clone)]
+  #[pyclass(str, eq)]
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
   pub struct SearchesRes {
     #[pyo3(get, set)]
-    platform: String,
+    platform: PlatformType,
 
     #[pyo3(get, set)]
+    #[serde(rename = "searches", default)]
     result: Vec<SearchRes>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    ignored: Vec<SearchFilter>,
+
+    #[pyo3(get, set)]
+    #[serde(default)]
+    next: Option<PageParam>,
   }
 
   impl Display for SearchesRes {
@@ -274,13 +1079,54 @@ mod trending {
     }
   }
 
+  data_class_methods!(SearchesRes, ["platform", "result", "ignored", "next"]);
+
   impl From<RSearchesRes> for SearchesRes {
     fn from(value: RSearchesRes) -> Self {
       Self {
-        platform: value.platform.to_str().to_string(),
+        platform: value.platform.into(),
         result: value.result.into_iter().map(|r| r.into()).collect(),
+        ignored: value.ignored.into_iter().map(|f| f.into()).collect(),
+        next: value.next.map(|p| p.into()),
+      }
+    }
+  }
+
+  #[pyfunction]
+  pub fn to_records<'py>(
+    py: Python<'py>,
+    results: Vec<Bound<'py, PyAny>>,
+  ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let mut records = Vec::new();
+    for res in results {
+      let (platform, items) = if let Ok(res) = res.cast::<TrendingsRes>() {
+        let res = res.borrow();
+        let items = res
+          .result
+          .iter()
+          .map(|r| to_dict(py, r))
+          .collect::<PyResult<Vec<_>>>()?;
+        (res.platform.to_string(), items)
+      } else {
+        let res = res.cast::<SearchesRes>()?.borrow();
+        let items = res
+          .result
+          .iter()
+          .map(|r| to_dict(py, r))
+          .collect::<PyResult<Vec<_>>>()?;
+        (res.platform.to_string(), items)
+      };
+      for (rank, item) in items.into_iter().enumerate() {
+        let record = item.cast_into::<PyDict>()?;
+        record.set_item("platform", &platform)?;
+        // the rank given by the platform wins over the position in the list
+        if record.get_item("rank")?.is_none_or(|r| r.is_none()) {
+          record.set_item("rank", rank + 1)?;
+        }
+        records.push(record);
       }
     }
+    Ok(records)
   }
 
   #[pymethods]
@@ -296,50 +1142,287 @@ mod trending {
       Ok(Self { client })
     }
 
-    pub fn trending_zhihu(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_zhihu()?;
+    #[pyo3(signature = (platform, req = None))]
+    pub fn trending(
+      &self,
+      py: Python<'_>,
+      platform: PlatformType,
+      req: Option<TrendingReq>,
+    ) -> Result<TrendingsRes> {
+      let platform: RPlatformType = platform.into();
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, platform.clone(), || {
+        self.client.trending_with_req(&platform, &req)
+      })?;
       Ok(res.into())
     }
 
-    pub fn trending_weibo(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_weibo()?;
+    pub fn search(
+      &self,
+      py: Python<'_>,
+      platform: PlatformType,
+      req: SearchReq,
+    ) -> Result<SearchesRes> {
+      let platform: RPlatformType = platform.into();
+      let req: RSearchReq = req.into();
+      let res = detach(py, platform.clone(), || self.client.search(&platform, &req))?;
       Ok(res.into())
     }
 
-    pub fn trending_toutiao(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_toutiao()?;
+    /// Walks the pages of a search until they run out or `limit` results were read.
+    #[pyo3(signature = (platform, req, limit = None))]
+    pub fn search_all(
+      &self,
+      py: Python<'_>,
+      platform: PlatformType,
+      req: SearchReq,
+      limit: Option<usize>,
+    ) -> Result<Vec<SearchRes>> {
+      let platform: RPlatformType = platform.into();
+      let req: RSearchReq = req.into();
+      let res = detach(py, platform.clone(), || {
+        self
+          .client
+          .search_iter(&platform, &req, limit)
+          .collect::<std::result::Result<Vec<_>, _>>()
+      })?;
+      Ok(res.into_iter().map(|r| r.into()).collect())
+    }
+
+    pub fn trending_zhihu(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Zhihu, || self.client.trending_zhihu())?;
       Ok(res.into())
     }
 
-    pub fn trending_tencent(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_tencent()?;
+    pub fn search_zhihu(&self, py: Python<'_>, req: SearchReq) -> Result<SearchesRes> {
+      let req: RSearchReq = req.into();
+      let res = detach(py, RPlatformType::Zhihu, || self.client.search_zhihu(&req))?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_weibo(&self, py: Python<'_>, req: Option<TrendingReq>) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Weibo, || {
+        self.client.trending_weibo_with_req(&req)
+      })?;
       Ok(res.into())
     }
 
-    pub fn search_tecent(&self, req: SearchReq) -> Result<SearchesRes> {
+    pub fn search_weibo(&self, py: Python<'_>, req: SearchReq) -> Result<SearchesRes> {
       let req: RSearchReq = req.into();
-      let res = self.client.search_tencent(&req)?;
+      let res = detach(py, RPlatformType::Weibo, || self.client.search_weibo(&req))?;
+      Ok(res.into())
+    }
+
+    pub fn trending_toutiao(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Toutiao, || {
+        self.client.trending_toutiao()
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn search_toutiao(&self, py: Python<'_>, req: SearchReq) -> Result<SearchesRes> {
+      let req: RSearchReq = req.into();
+      let res = detach(py, RPlatformType::Toutiao, || {
+        self.client.search_toutiao(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_tencent(
+      &self,
+      py: Python<'_>,
+      req: Option<TrendingReq>,
+    ) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Tencent, || {
+        self.client.trending_tencent_with_req(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn search_tecent(&self, py: Python<'_>, req: SearchReq) -> Result<SearchesRes> {
+      let req: RSearchReq = req.into();
+      let res = detach(py, RPlatformType::Tencent, || {
+        self.client.search_tencent(&req)
+      })?;
       Ok(res.into())
     }
 
-    pub fn trending_tieba(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_tieba()?;
+    #[pyo3(signature = (req = None))]
+    pub fn trending_tieba(&self, py: Python<'_>, req: Option<TrendingReq>) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Tieba, || {
+        self.client.trending_tieba_with_req(&req)
+      })?;
       Ok(res.into())
     }
 
-    pub fn trending_netease(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_netease()?;
+    pub fn tieba_topic(&self, py: Python<'_>, topic_id: u64) -> Result<TopicRes> {
+      let res = detach(py, RPlatformType::Tieba, || {
+        self.client.tieba_topic(topic_id)
+      })?;
       Ok(res.into())
     }
 
-    pub fn search_netease(&self, req: SearchReq) -> Result<SearchesRes> {
+    pub fn trending_netease(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Netease, || {
+        self.client.trending_netease()
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn search_netease(&self, py: Python<'_>, req: SearchReq) -> Result<SearchesRes> {
       let req: RSearchReq = req.into();
-      let res = self.client.search_netease(&req)?;
+      let res = detach(py, RPlatformType::Netease, || {
+        self.client.search_netease(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_hupu(&self, py: Python<'_>, req: Option<TrendingReq>) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Hupu, || {
+        self.client.trending_hupu_with_req(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn search_hupu(&self, py: Python<'_>, req: SearchReq) -> Result<SearchesRes> {
+      let req: RSearchReq = req.into();
+      let res = detach(py, RPlatformType::Hupu, || self.client.search_hupu(&req))?;
+      Ok(res.into())
+    }
+
+    pub fn trending_bilibili(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Bilibili, || {
+        self.client.trending_bilibili()
+      })?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (rid = 0))]
+    pub fn ranking_bilibili(&self, py: Python<'_>, rid: u32) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Bilibili, || {
+        self.client.ranking_bilibili(rid)
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn trending_douyin(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Douyin, || self.client.trending_douyin())?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_baidu(&self, py: Python<'_>, req: Option<TrendingReq>) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Baidu, || {
+        self.client.trending_baidu_with_req(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_douban(
+      &self,
+      py: Python<'_>,
+      req: Option<TrendingReq>,
+    ) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Douban, || {
+        self.client.trending_douban_with_req(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn trending_kuaishou(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Kuaishou, || {
+        self.client.trending_kuaishou()
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn trending_kr36(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Kr36, || self.client.trending_kr36())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_huxiu(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Huxiu, || self.client.trending_huxiu())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_ithome(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Ithome, || self.client.trending_ithome())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_sspai(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Sspai, || self.client.trending_sspai())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_juejin(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Juejin, || self.client.trending_juejin())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_v2ex(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::V2ex, || self.client.trending_v2ex())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_csdn(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Csdn, || self.client.trending_csdn())?;
+      Ok(res.into())
+    }
+
+    pub fn trending_hackernews(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::HackerNews, || {
+        self.client.trending_hackernews()
+      })?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_reddit(
+      &self,
+      py: Python<'_>,
+      req: Option<TrendingReq>,
+    ) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Reddit, || {
+        self.client.trending_reddit_with_req(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    #[pyo3(signature = (req = None))]
+    pub fn trending_github(
+      &self,
+      py: Python<'_>,
+      req: Option<TrendingReq>,
+    ) -> Result<TrendingsRes> {
+      let req: RTrendingReq = req.map(|r| r.into()).unwrap_or_default();
+      let res = detach(py, RPlatformType::Github, || {
+        self.client.trending_github_with_req(&req)
+      })?;
+      Ok(res.into())
+    }
+
+    pub fn trending_thepaper(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Thepaper, || {
+        self.client.trending_thepaper()
+      })?;
       Ok(res.into())
     }
 
-    pub fn trending_hupu(&self) -> Result<TrendingsRes> {
-      let res = self.client.trending_hupu()?;
+    pub fn trending_ifeng(&self, py: Python<'_>) -> Result<TrendingsRes> {
+      let res = detach(py, RPlatformType::Ifeng, || self.client.trending_ifeng())?;
       Ok(res.into())
     }
   }
@@ -0,0 +1,258 @@
+from datetime import timedelta
+from typing import Any, Optional, Sequence, Union
+
+class TrendingError(Exception):
+    platform: Optional[str]
+    endpoint: Optional[str]
+    status: Optional[int]
+
+class HttpStatusError(TrendingError): ...
+class RequestTimeoutError(TrendingError): ...
+class DecodeError(TrendingError): ...
+
+class PlatformType:
+    Zhihu: PlatformType
+    Weibo: PlatformType
+    Toutiao: PlatformType
+    Tencent: PlatformType
+    Tieba: PlatformType
+    Netease: PlatformType
+    Hupu: PlatformType
+    Bilibili: PlatformType
+    Douyin: PlatformType
+    Baidu: PlatformType
+    Douban: PlatformType
+    Kuaishou: PlatformType
+    Kr36: PlatformType
+    Huxiu: PlatformType
+    Ithome: PlatformType
+    Sspai: PlatformType
+    Juejin: PlatformType
+    V2ex: PlatformType
+    Csdn: PlatformType
+    HackerNews: PlatformType
+    Reddit: PlatformType
+    Github: PlatformType
+    Thepaper: PlatformType
+    Ifeng: PlatformType
+    Other: PlatformType
+    def search_filters(self) -> list[SearchFilter]: ...
+
+class SearchType:
+    News: SearchType
+    Video: SearchType
+    Account: SearchType
+
+class SearchSort:
+    Relevance: SearchSort
+    Newest: SearchSort
+    Hottest: SearchSort
+
+class SearchFilter:
+    Since: SearchFilter
+    Until: SearchFilter
+    Sort: SearchFilter
+    Kind: SearchFilter
+    Size: SearchFilter
+    def is_client_side(self) -> bool: ...
+
+class MediaType:
+    Video: MediaType
+    Audio: MediaType
+    Image: MediaType
+    Other: MediaType
+
+class PageParam:
+    class First(PageParam):
+        def __init__(self) -> None: ...
+
+    class Other(PageParam):
+        def __init__(self, page: int) -> None: ...
+
+    class Offset(PageParam):
+        def __init__(self, offset: int) -> None: ...
+
+    class Cursor(PageParam):
+        def __init__(self, cursor: str) -> None: ...
+
+class ClientOptions:
+    headers: dict[str, str]
+    timeout: Optional[timedelta]
+    retries: Optional[int]
+    cache_ttl: Optional[timedelta]
+    def __init__(
+        self,
+        headers: Optional[dict[str, str]] = None,
+        timeout: Optional[timedelta] = None,
+        proxy: Optional[str] = None,
+        retries: Optional[int] = None,
+        cache_ttl: Optional[timedelta] = None,
+        platform_headers: Optional[dict[PlatformType, dict[str, str]]] = None,
+    ) -> None: ...
+    def with_headers(self, headers: dict[str, str]) -> None: ...
+    def with_header(self, name: str, value: str) -> None: ...
+    def with_platform_headers(
+        self, platform: PlatformType, headers: dict[str, str]
+    ) -> None: ...
+    def with_platform_header(
+        self, platform: PlatformType, name: str, value: str
+    ) -> None: ...
+    def with_proxy(self, proxy: str) -> None: ...
+    def with_timeout(self, timeout: timedelta) -> None: ...
+    def with_retries(self, retries: int) -> None: ...
+    def with_cache_ttl(self, ttl: timedelta) -> None: ...
+    def debug_print(self) -> None: ...
+
+class TrendingRes:
+    title: str
+    url: str
+    trend: Optional[str]
+    medias: Optional[list[MediaData]]
+    label: Optional[str]
+    desc: Optional[str]
+    author: Optional[str]
+    time: Optional[int]
+    reads: Optional[int]
+    comments: Optional[int]
+    rank: Optional[int]
+    language: Optional[str]
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> TrendingRes: ...
+
+class TrendingsRes:
+    platform: PlatformType
+    result: list[TrendingRes]
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> TrendingsRes: ...
+
+class TopicRes:
+    platform: PlatformType
+    title: str
+    url: str
+    desc: Optional[str]
+    discussions: Optional[int]
+    medias: Optional[list[MediaData]]
+    posts: list[TrendingRes]
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> TopicRes: ...
+
+class TrendingReq:
+    board: Optional[str]
+    params: dict[str, str]
+    def __init__(
+        self, board: Optional[str] = None, params: Optional[dict[str, str]] = None
+    ) -> None: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> TrendingReq: ...
+
+class SearchReq:
+    keyword: str
+    page: Optional[PageParam]
+    size: Optional[int]
+    kind: Optional[SearchType]
+    since: Optional[int]
+    until: Optional[int]
+    sort: Optional[SearchSort]
+    def __init__(
+        self,
+        keyword: str,
+        page: Union[PageParam, int, None] = None,
+        size: Optional[int] = None,
+        kind: Optional[SearchType] = None,
+        since: Optional[int] = None,
+        until: Optional[int] = None,
+        sort: Optional[SearchSort] = None,
+    ) -> None: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> SearchReq: ...
+
+class MediaData:
+    url: str
+    kind: MediaType
+    desc: Optional[str]
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> MediaData: ...
+
+class SearchRes:
+    title: str
+    url: str
+    time: Optional[int]
+    medias: Optional[list[MediaData]]
+    desc: Optional[str]
+    author: Optional[str]
+    likes: Optional[int]
+    comments: Optional[int]
+    kind: Optional[SearchType]
+    category: Optional[str]
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> SearchRes: ...
+
+class SearchesRes:
+    platform: PlatformType
+    result: list[SearchRes]
+    ignored: list[SearchFilter]
+    next: Optional[PageParam]
+    def to_dict(self) -> dict[str, Any]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> SearchesRes: ...
+
+def to_records(
+    results: Sequence[Union[TrendingsRes, SearchesRes]],
+) -> list[dict[str, Any]]: ...
+
+class BlockClient:
+    def __init__(self, options: Optional[ClientOptions] = None) -> None: ...
+    def trending(
+        self, platform: PlatformType, req: Optional[TrendingReq] = None
+    ) -> TrendingsRes: ...
+    def search(self, platform: PlatformType, req: SearchReq) -> SearchesRes: ...
+    def search_all(
+        self, platform: PlatformType, req: SearchReq, limit: Optional[int] = None
+    ) -> list[SearchRes]: ...
+    def trending_zhihu(self) -> TrendingsRes: ...
+    def search_zhihu(self, req: SearchReq) -> SearchesRes: ...
+    def trending_weibo(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def search_weibo(self, req: SearchReq) -> SearchesRes: ...
+    def trending_toutiao(self) -> TrendingsRes: ...
+    def search_toutiao(self, req: SearchReq) -> SearchesRes: ...
+    def trending_tencent(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def search_tecent(self, req: SearchReq) -> SearchesRes: ...
+    def trending_tieba(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def tieba_topic(self, topic_id: int) -> TopicRes: ...
+    def trending_netease(self) -> TrendingsRes: ...
+    def search_netease(self, req: SearchReq) -> SearchesRes: ...
+    def trending_hupu(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def search_hupu(self, req: SearchReq) -> SearchesRes: ...
+    def trending_bilibili(self) -> TrendingsRes: ...
+    def ranking_bilibili(self, rid: int = 0) -> TrendingsRes: ...
+    def trending_douyin(self) -> TrendingsRes: ...
+    def trending_baidu(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def trending_douban(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def trending_kuaishou(self) -> TrendingsRes: ...
+    def trending_kr36(self) -> TrendingsRes: ...
+    def trending_huxiu(self) -> TrendingsRes: ...
+    def trending_ithome(self) -> TrendingsRes: ...
+    def trending_sspai(self) -> TrendingsRes: ...
+    def trending_juejin(self) -> TrendingsRes: ...
+    def trending_v2ex(self) -> TrendingsRes: ...
+    def trending_csdn(self) -> TrendingsRes: ...
+    def trending_hackernews(self) -> TrendingsRes: ...
+    def trending_reddit(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def trending_github(self, req: Optional[TrendingReq] = None) -> TrendingsRes: ...
+    def trending_thepaper(self) -> TrendingsRes: ...
+    def trending_ifeng(self) -> TrendingsRes: ...
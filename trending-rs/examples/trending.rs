@@ -1,4 +1,3 @@
-use tokio;
 use trending::{client::AsyncClient, common::SearchReq, errors::Result};
 
 #[tokio::main(flavor = "current_thread")]
@@ -0,0 +1,143 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingReq, TrendingRes, TrendingsRes, http_get,
+    not_empty_str,
+  },
+  errors::{Result, TrendingError, UnsupportedBoardSnafu},
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://top.baidu.com/api/board";
+
+/// Boards of baidu hot search, the first one is used when the request has no board.
+pub const BOARDS: [&str; 6] = ["realtime", "novel", "movie", "teleplay", "car", "game"];
+
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  http_get::<BaiduTrendingReq, EmptyType, BaiduRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  block_http_get::<BaiduTrendingReq, EmptyType, BaiduRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BaiduTrendingReq {
+  #[serde(rename = "platform")]
+  platform: &'static str,
+
+  #[serde(rename = "tab")]
+  tab: &'static str,
+}
+
+impl TryFrom<&TrendingReq> for BaiduTrendingReq {
+  type Error = TrendingError;
+
+  fn try_from(value: &TrendingReq) -> Result<Self> {
+    let board = value.board.as_deref().unwrap_or(BOARDS[0]);
+    match BOARDS.iter().find(|b| **b == board) {
+      Some(tab) => Ok(Self {
+        platform: "wise",
+        tab,
+      }),
+      None => UnsupportedBoardSnafu {
+        platform: PlatformType::Baidu.to_str(),
+        board,
+      }
+      .fail(),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BaiduRes {
+  #[serde(rename = "data")]
+  data: BaiduData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BaiduData {
+  #[serde(rename = "cards", skip_serializing_if = "Vec::is_empty", default)]
+  cards: Vec<BaiduCard>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BaiduCard {
+  #[serde(rename = "content", skip_serializing_if = "Vec::is_empty", default)]
+  content: Vec<BaiduWord>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BaiduWord {
+  #[serde(rename = "word")]
+  word: String,
+
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "hotScore", skip_serializing_if = "Option::is_none")]
+  hot_score: Option<String>,
+
+  #[serde(rename = "desc", skip_serializing_if = "Option::is_none")]
+  desc: Option<String>,
+
+  #[serde(rename = "img", skip_serializing_if = "Option::is_none")]
+  img: Option<String>,
+}
+
+impl From<BaiduWord> for TrendingRes {
+  fn from(value: BaiduWord) -> Self {
+    Self {
+      title: value.word,
+      url: value.url,
+      trend: not_empty_str(value.hot_score),
+      medias: not_empty_str(value.img).map(|i| vec![MediaData::new_image(i)]),
+      desc: not_empty_str(value.desc),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<BaiduRes> for TrendingsRes {
+  fn from(value: BaiduRes) -> Self {
+    Self {
+      platform: PlatformType::Baidu,
+      result: value
+        .data
+        .cards
+        .into_iter()
+        .flat_map(|c| c.content)
+        .map(|r| r.into())
+        .collect(),
+    }
+  }
+}
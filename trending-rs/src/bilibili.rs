@@ -0,0 +1,136 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, http_get},
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://api.bilibili.com/x/web-interface/popular?ps=50&pn=1";
+pub const RANKING_ENDPOINT: &str = "https://api.bilibili.com/x/web-interface/ranking/v2";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, BilibiliRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+pub async fn ranking(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  rid: u32,
+) -> Result<TrendingsRes> {
+  http_get::<BilibiliRankingReq, EmptyType, BilibiliRes>(
+    client,
+    RANKING_ENDPOINT,
+    headers,
+    Some(&rid.into()),
+    None,
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, BilibiliRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    None,
+  )
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_ranking(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  rid: u32,
+) -> Result<TrendingsRes> {
+  block_http_get::<BilibiliRankingReq, EmptyType, BilibiliRes>(
+    client,
+    RANKING_ENDPOINT,
+    headers,
+    Some(&rid.into()),
+    None,
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BilibiliRankingReq {
+  #[serde(rename = "rid")]
+  rid: u32,
+
+  #[serde(rename = "type")]
+  kind: &'static str,
+}
+
+impl From<u32> for BilibiliRankingReq {
+  fn from(value: u32) -> Self {
+    Self {
+      rid: value,
+      kind: "all",
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BilibiliRes {
+  #[serde(rename = "data")]
+  data: BilibiliData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BilibiliData {
+  #[serde(rename = "list", skip_serializing_if = "Vec::is_empty", default)]
+  list: Vec<BilibiliVideo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BilibiliVideo {
+  #[serde(rename = "bvid")]
+  bvid: String,
+
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "pic", skip_serializing_if = "Option::is_none")]
+  pic: Option<String>,
+
+  #[serde(rename = "stat")]
+  stat: BilibiliStat,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BilibiliStat {
+  #[serde(rename = "view")]
+  view: u64,
+}
+
+impl From<BilibiliVideo> for TrendingRes {
+  fn from(value: BilibiliVideo) -> Self {
+    Self {
+      title: value.title,
+      url: format!("https://www.bilibili.com/video/{}", value.bvid),
+      trend: Some(value.stat.view.to_string()),
+      medias: value.pic.map(|p| vec![MediaData::new_image(p)]),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<BilibiliRes> for TrendingsRes {
+  fn from(value: BilibiliRes) -> Self {
+    Self {
+      platform: PlatformType::Bilibili,
+      result: value.data.list.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
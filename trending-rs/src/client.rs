@@ -1,90 +1,641 @@
-use std::time::Duration;
+use std::{
+  collections::{HashMap, HashSet, VecDeque},
+  sync::Mutex,
+  time::Duration,
+};
+
+use futures_util::{Stream, stream};
 
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockHttpClient;
 use reqwest::{
-  Client as AsyncHttpClient, Proxy,
+  Client as AsyncHttpClient,
   header::{AsHeaderName, HeaderMap, HeaderName, HeaderValue},
 };
 use snafu::ResultExt;
+use web_time::Instant;
 
 use crate::{
-  common::{SearchReq, SearchesRes, TrendingsRes},
-  errors::{ReqwestClientSnafu, Result},
+  common::{
+    PageParam, PlatformType, SearchReq, SearchRes, SearchesRes, TopicRes, TrendingReq, TrendingsRes,
+  },
+  errors::{ReqwestClientSnafu, Result, TrendingError, UnsupportedPlatformSnafu},
 };
 
 pub struct AsyncClient {
   client: AsyncHttpClient,
+  #[cfg(target_arch = "wasm32")]
+  timeout: Option<Duration>,
+  retries: u32,
+  cache: TrendingCache,
+  platform_headers: HashMap<PlatformType, HeaderMap>,
 }
 
 impl AsyncClient {
   pub fn new() -> Self {
     let client = AsyncHttpClient::new();
-    Self { client }
+    Self {
+      client,
+      #[cfg(target_arch = "wasm32")]
+      timeout: None,
+      retries: 0,
+      cache: TrendingCache::new(None),
+      platform_headers: HashMap::new(),
+    }
   }
 
   pub fn new_with_options(options: ClientOptions) -> Result<Self> {
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
     let mut client_builder = AsyncHttpClient::builder();
-    if let Some(timeout) = options.timeout {
-      client_builder = client_builder.timeout(timeout);
-    }
-    if let Some(proxy) = options.proxy {
-      client_builder = client_builder.proxy(proxy);
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+      if let Some(timeout) = options.timeout {
+        client_builder = client_builder.timeout(timeout);
+      }
+      if let Some(proxy) = options.proxy {
+        client_builder = client_builder.proxy(proxy);
+      }
     }
     let client = client_builder
       .default_headers(options.headers)
       .build()
       .context(ReqwestClientSnafu)?;
-    Ok(AsyncClient { client })
+    Ok(AsyncClient {
+      client,
+      #[cfg(target_arch = "wasm32")]
+      timeout: options.timeout,
+      retries: options.retries.unwrap_or(0),
+      cache: TrendingCache::new(options.cache_ttl),
+      platform_headers: options.platform_headers,
+    })
+  }
+
+  pub async fn trending(&self, platform: &PlatformType) -> Result<TrendingsRes> {
+    self
+      .trending_with_req(platform, &TrendingReq::default())
+      .await
+  }
+
+  /// Like [`AsyncClient::trending`], platforms without boards ignore `req`.
+  pub async fn trending_with_req(
+    &self,
+    platform: &PlatformType,
+    req: &TrendingReq,
+  ) -> Result<TrendingsRes> {
+    match platform {
+      PlatformType::Zhihu => self.trending_zhihu().await,
+      PlatformType::Weibo => self.trending_weibo_with_req(req).await,
+      PlatformType::Toutiao => self.trending_toutiao().await,
+      PlatformType::Tencent => self.trending_tencent_with_req(req).await,
+      PlatformType::Tieba => self.trending_tieba_with_req(req).await,
+      PlatformType::Netease => self.trending_netease().await,
+      PlatformType::Hupu => self.trending_hupu_with_req(req).await,
+      PlatformType::Bilibili => self.trending_bilibili().await,
+      PlatformType::Douyin => self.trending_douyin().await,
+      PlatformType::Baidu => self.trending_baidu_with_req(req).await,
+      PlatformType::Douban => self.trending_douban_with_req(req).await,
+      PlatformType::Kuaishou => self.trending_kuaishou().await,
+      PlatformType::Kr36 => self.trending_kr36().await,
+      PlatformType::Huxiu => self.trending_huxiu().await,
+      PlatformType::Ithome => self.trending_ithome().await,
+      PlatformType::Sspai => self.trending_sspai().await,
+      PlatformType::Juejin => self.trending_juejin().await,
+      PlatformType::V2ex => self.trending_v2ex().await,
+      PlatformType::Csdn => self.trending_csdn().await,
+      PlatformType::HackerNews => self.trending_hackernews().await,
+      PlatformType::Reddit => self.trending_reddit_with_req(req).await,
+      PlatformType::Github => self.trending_github_with_req(req).await,
+      PlatformType::Thepaper => self.trending_thepaper().await,
+      PlatformType::Ifeng => self.trending_ifeng().await,
+      PlatformType::Other(other) => UnsupportedPlatformSnafu { platform: other }.fail(),
+    }
+  }
+
+  pub async fn search(&self, platform: &PlatformType, req: &SearchReq) -> Result<SearchesRes> {
+    match platform {
+      PlatformType::Tencent => self.search_tencent(req).await,
+      PlatformType::Netease => self.search_netease(req).await,
+      PlatformType::Hupu => self.search_hupu(req).await,
+      PlatformType::Toutiao => self.search_toutiao(req).await,
+      PlatformType::Weibo => self.search_weibo(req).await,
+      PlatformType::Zhihu => self.search_zhihu(req).await,
+      other => UnsupportedPlatformSnafu {
+        platform: other.to_str(),
+      }
+      .fail(),
+    }
+  }
+
+  /// Walks the pages of a search from the page of `req`, until a page brings
+  /// no new results or `limit` results were yielded. Errors end the stream.
+  /// The time window of `req` only drops results, the walk goes on past pages
+  /// it empties.
+  pub fn search_stream<'a>(
+    &'a self,
+    platform: &'a PlatformType,
+    req: &SearchReq,
+    limit: Option<usize>,
+  ) -> impl Stream<Item = Result<SearchRes>> + 'a {
+    stream::unfold(SearchPager::new(req, limit), move |mut pager| async move {
+      loop {
+        if let Some(item) = pager.next_item() {
+          return Some((item, pager));
+        }
+        let req = pager.next_req()?;
+        let res = self.search(platform, &req).await;
+        pager.push_page(res);
+      }
+    })
   }
 
   pub async fn trending_zhihu(&self) -> Result<TrendingsRes> {
-    crate::zhihu::trending(&self.client).await
+    self
+      .cached(PlatformType::Zhihu, |headers| {
+        crate::zhihu::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn search_zhihu(&self, req: &SearchReq) -> Result<SearchesRes> {
+    self
+      .searched(PlatformType::Zhihu, req, |headers| {
+        crate::zhihu::search(&self.client, headers, req)
+      })
+      .await
   }
 
   pub async fn trending_weibo(&self) -> Result<TrendingsRes> {
-    crate::weibo::trending(&self.client).await
+    self.trending_weibo_with_req(&TrendingReq::default()).await
+  }
+
+  /// Weibo boards are `hot`, `realtime`, `entertainment` and `topic`, topics carry
+  /// read and discussion counts. `entertainment` and `topic` need a logged in
+  /// `cookie` header for weibo and fail without one.
+  pub async fn trending_weibo_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Weibo, req, |headers| {
+        crate::weibo::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  pub async fn search_weibo(&self, req: &SearchReq) -> Result<SearchesRes> {
+    self
+      .searched(PlatformType::Weibo, req, |headers| {
+        crate::weibo::search(&self.client, headers, req)
+      })
+      .await
   }
 
   pub async fn trending_toutiao(&self) -> Result<TrendingsRes> {
-    crate::toutiao::trending(&self.client).await
+    self
+      .cached(PlatformType::Toutiao, |headers| {
+        crate::toutiao::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn search_toutiao(&self, req: &SearchReq) -> Result<SearchesRes> {
+    self
+      .searched(PlatformType::Toutiao, req, |headers| {
+        crate::toutiao::search(&self.client, headers, req)
+      })
+      .await
   }
 
   pub async fn trending_tencent(&self) -> Result<TrendingsRes> {
-    crate::tencent::trending(&self.client).await
+    self
+      .trending_tencent_with_req(&TrendingReq::default())
+      .await
+  }
+
+  /// Hot ranking of tencent news, the board selects another rank list, see
+  /// [`TrendingReq::with_offset`] and [`TrendingReq::with_size`] for paging.
+  pub async fn trending_tencent_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Tencent, req, |headers| {
+        crate::tencent::trending(&self.client, headers, req)
+      })
+      .await
   }
 
   pub async fn search_tencent(&self, req: &SearchReq) -> Result<SearchesRes> {
-    crate::tencent::search(&self.client, req).await
+    self
+      .searched(PlatformType::Tencent, req, |headers| {
+        crate::tencent::search(&self.client, headers, req)
+      })
+      .await
   }
 
   pub async fn trending_tieba(&self) -> Result<TrendingsRes> {
-    crate::tieba::trending(&self.client).await
+    self.trending_tieba_with_req(&TrendingReq::default()).await
+  }
+
+  /// Hot topics of tieba, a board switches to the hot threads of the forum with
+  /// that name, see [`TrendingReq::with_board`].
+  pub async fn trending_tieba_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Tieba, req, |headers| {
+        crate::tieba::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  /// Description, discussion count and top posts of a tieba hot topic.
+  pub async fn tieba_topic(&self, topic_id: u64) -> Result<TopicRes> {
+    self
+      .retry(&PlatformType::Tieba, |headers| {
+        crate::tieba::topic(&self.client, headers, topic_id)
+      })
+      .await
   }
 
   pub async fn trending_netease(&self) -> Result<TrendingsRes> {
-    crate::netease::trending(&self.client).await
+    self
+      .cached(PlatformType::Netease, |headers| {
+        crate::netease::trending(&self.client, headers)
+      })
+      .await
   }
 
   pub async fn search_netease(&self, req: &SearchReq) -> Result<SearchesRes> {
-    crate::netease::search(&self.client, req).await
+    self
+      .searched(PlatformType::Netease, req, |headers| {
+        crate::netease::search(&self.client, headers, req)
+      })
+      .await
   }
 
   pub async fn trending_hupu(&self) -> Result<TrendingsRes> {
-    crate::hupu::trending(&self.client).await
+    self.trending_hupu_with_req(&TrendingReq::default()).await
+  }
+
+  /// Hot threads of a hupu board, see [`TrendingReq::with_board`], defaults to `bxj`,
+  /// the `page` param selects the page.
+  pub async fn trending_hupu_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Hupu, req, |headers| {
+        crate::hupu::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  pub async fn search_hupu(&self, req: &SearchReq) -> Result<SearchesRes> {
+    self
+      .searched(PlatformType::Hupu, req, |headers| {
+        crate::hupu::search(&self.client, headers, req)
+      })
+      .await
+  }
+
+  pub async fn trending_bilibili(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Bilibili, |headers| {
+        crate::bilibili::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  /// Video ranking of a bilibili category, `rid` 0 means all categories.
+  pub async fn ranking_bilibili(&self, rid: u32) -> Result<TrendingsRes> {
+    self
+      .retry(&PlatformType::Bilibili, |headers| {
+        crate::bilibili::ranking(&self.client, headers, rid)
+      })
+      .await
+  }
+
+  /// Douyin may answer with an empty list without a `cookie`, see
+  /// [`ClientOptions::with_platform_header`].
+  pub async fn trending_douyin(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Douyin, |headers| {
+        crate::douyin::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_baidu(&self) -> Result<TrendingsRes> {
+    self.trending_baidu_with_req(&TrendingReq::default()).await
+  }
+
+  /// Hot search of a baidu board, see [`TrendingReq::with_board`], defaults to `realtime`.
+  pub async fn trending_baidu_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Baidu, req, |headers| {
+        crate::baidu::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  pub async fn trending_douban(&self) -> Result<TrendingsRes> {
+    self.trending_douban_with_req(&TrendingReq::default()).await
+  }
+
+  /// Hot group topics or weekly charts of douban, boards are `group`, `movie` and `book`.
+  pub async fn trending_douban_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Douban, req, |headers| {
+        crate::douban::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  /// Hot board of kuaishou ordered by rank, see [`crate::common::TrendingRes::rank`].
+  pub async fn trending_kuaishou(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Kuaishou, |headers| {
+        crate::kuaishou::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_kr36(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Kr36, |headers| {
+        crate::kr36::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  /// Latest articles of huxiu, huxiu publishes no hot list.
+  pub async fn trending_huxiu(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Huxiu, |headers| {
+        crate::huxiu::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_ithome(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Ithome, |headers| {
+        crate::ithome::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_sspai(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Sspai, |headers| {
+        crate::sspai::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_juejin(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Juejin, |headers| {
+        crate::juejin::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_v2ex(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::V2ex, |headers| {
+        crate::v2ex::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_csdn(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Csdn, |headers| {
+        crate::csdn::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_hackernews(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::HackerNews, |headers| {
+        crate::hackernews::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_reddit(&self) -> Result<TrendingsRes> {
+    self.trending_reddit_with_req(&TrendingReq::default()).await
+  }
+
+  /// Hot posts of a subreddit taken from the board, defaults to `popular`.
+  pub async fn trending_reddit_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Reddit, req, |headers| {
+        crate::reddit::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  pub async fn trending_github(&self) -> Result<TrendingsRes> {
+    self.trending_github_with_req(&TrendingReq::default()).await
+  }
+
+  /// Trending repositories, filtered by the `language` and `since` params.
+  pub async fn trending_github_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self
+      .cached_with_req(PlatformType::Github, req, |headers| {
+        crate::github::trending(&self.client, headers, req)
+      })
+      .await
+  }
+
+  pub async fn trending_thepaper(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Thepaper, |headers| {
+        crate::thepaper::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  pub async fn trending_ifeng(&self) -> Result<TrendingsRes> {
+    self
+      .cached(PlatformType::Ifeng, |headers| {
+        crate::ifeng::trending(&self.client, headers)
+      })
+      .await
+  }
+
+  async fn retry<T, F, Fut>(&self, platform: &PlatformType, f: F) -> Result<T>
+  where
+    F: Fn(Option<HeaderMap>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+  {
+    let mut attempt = 0;
+    loop {
+      match self
+        .attempt(f(self.platform_headers.get(platform).cloned()))
+        .await
+      {
+        Err(err) if attempt < self.retries && err.is_retryable() => {
+          futures_timer::Delay::new(retry_delay(&err, attempt)).await;
+          attempt += 1;
+        }
+        res => return res,
+      }
+    }
+  }
+
+  /// Runs one request, bounded by the timeout on wasm32 where reqwest has no
+  /// client wide timeout.
+  async fn attempt<T>(&self, fut: impl Future<Output = Result<T>>) -> Result<T> {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(timeout) = self.timeout {
+      let fut = std::pin::pin!(fut);
+      return match futures_util::future::select(fut, futures_timer::Delay::new(timeout)).await {
+        futures_util::future::Either::Left((res, _)) => res,
+        futures_util::future::Either::Right(_) => {
+          crate::errors::RequestTimeoutSnafu { timeout }.fail()
+        }
+      };
+    }
+    fut.await
+  }
+
+  async fn searched<F, Fut>(
+    &self,
+    platform: PlatformType,
+    req: &SearchReq,
+    f: F,
+  ) -> Result<SearchesRes>
+  where
+    F: Fn(Option<HeaderMap>) -> Fut,
+    Fut: Future<Output = Result<SearchesRes>>,
+  {
+    self.retry(&platform, f).await.map(|r| r.filtered(req))
+  }
+
+  async fn cached<F, Fut>(&self, platform: PlatformType, f: F) -> Result<TrendingsRes>
+  where
+    F: Fn(Option<HeaderMap>) -> Fut,
+    Fut: Future<Output = Result<TrendingsRes>>,
+  {
+    self
+      .cached_with_req(platform, &TrendingReq::default(), f)
+      .await
+  }
+
+  async fn cached_with_req<F, Fut>(
+    &self,
+    platform: PlatformType,
+    req: &TrendingReq,
+    f: F,
+  ) -> Result<TrendingsRes>
+  where
+    F: Fn(Option<HeaderMap>) -> Fut,
+    Fut: Future<Output = Result<TrendingsRes>>,
+  {
+    if let Some(res) = self.cache.get(&platform, req) {
+      return Ok(res);
+    }
+    let res = self.retry(&platform, f).await?;
+    self.cache.put(platform, req, &res);
+    Ok(res)
+  }
+}
+
+impl Default for AsyncClient {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Page state shared by the search stream and iterator, following the `next`
+/// token of each page.
+struct SearchPager {
+  req: SearchReq,
+  next: Option<PageParam>,
+  remaining: Option<usize>,
+  buffer: VecDeque<Result<SearchRes>>,
+  seen: HashSet<String>,
+  done: bool,
+}
+
+impl SearchPager {
+  fn new(req: &SearchReq, limit: Option<usize>) -> Self {
+    Self {
+      req: req.clone(),
+      next: Some(req.page.clone().unwrap_or(PageParam::First)),
+      remaining: limit,
+      buffer: VecDeque::new(),
+      seen: HashSet::new(),
+      done: false,
+    }
+  }
+
+  fn next_item(&mut self) -> Option<Result<SearchRes>> {
+    if self.remaining == Some(0) {
+      self.done = true;
+      return None;
+    }
+    let item = self.buffer.pop_front()?;
+    if let Some(remaining) = self.remaining.as_mut() {
+      *remaining -= 1;
+    }
+    Some(item)
+  }
+
+  /// Request of the next page, `None` once the search is exhausted. The time
+  /// window is left out so that the raw page decides when the search ends,
+  /// [`SearchPager::push_page`] applies it afterwards.
+  fn next_req(&mut self) -> Option<SearchReq> {
+    if self.done {
+      return None;
+    }
+    let page = self.next.take()?;
+    let mut req = self.req.clone().with_page(page);
+    req.since = None;
+    req.until = None;
+    Some(req)
+  }
+
+  fn push_page(&mut self, res: Result<SearchesRes>) {
+    match res {
+      Ok(res) => {
+        // platforms ignoring the page answer the same results again
+        let fresh: Vec<SearchRes> = res
+          .result
+          .into_iter()
+          .filter(|r| self.seen.insert(r.url.clone()))
+          .collect();
+        self.done = fresh.is_empty() || res.next.is_none();
+        let res = SearchesRes {
+          result: fresh,
+          ..res
+        }
+        .filtered(&self.req);
+        self.next = res.next;
+        self.buffer.extend(res.result.into_iter().map(Ok));
+      }
+      Err(err) => {
+        self.done = true;
+        self.buffer.push_back(Err(err));
+      }
+    }
   }
 }
 
 #[cfg(feature = "blocking")]
 pub struct BlockClient {
   client: BlockHttpClient,
+  retries: u32,
+  cache: TrendingCache,
+  platform_headers: HashMap<PlatformType, HeaderMap>,
 }
 
 #[cfg(feature = "blocking")]
 impl BlockClient {
   pub fn new() -> Self {
     let client = BlockHttpClient::new();
-    Self { client }
+    Self {
+      client,
+      retries: 0,
+      cache: TrendingCache::new(None),
+      platform_headers: HashMap::new(),
+    }
   }
 
   pub fn new_with_options(options: ClientOptions) -> Result<Self> {
@@ -99,51 +650,448 @@ impl BlockClient {
       .default_headers(options.headers)
       .build()
       .context(ReqwestClientSnafu)?;
-    Ok(BlockClient { client })
+    Ok(BlockClient {
+      client,
+      retries: options.retries.unwrap_or(0),
+      cache: TrendingCache::new(options.cache_ttl),
+      platform_headers: options.platform_headers,
+    })
+  }
+
+  pub fn trending(&self, platform: &PlatformType) -> Result<TrendingsRes> {
+    self.trending_with_req(platform, &TrendingReq::default())
+  }
+
+  /// Like [`BlockClient::trending`], platforms without boards ignore `req`.
+  pub fn trending_with_req(
+    &self,
+    platform: &PlatformType,
+    req: &TrendingReq,
+  ) -> Result<TrendingsRes> {
+    match platform {
+      PlatformType::Zhihu => self.trending_zhihu(),
+      PlatformType::Weibo => self.trending_weibo_with_req(req),
+      PlatformType::Toutiao => self.trending_toutiao(),
+      PlatformType::Tencent => self.trending_tencent_with_req(req),
+      PlatformType::Tieba => self.trending_tieba_with_req(req),
+      PlatformType::Netease => self.trending_netease(),
+      PlatformType::Hupu => self.trending_hupu_with_req(req),
+      PlatformType::Bilibili => self.trending_bilibili(),
+      PlatformType::Douyin => self.trending_douyin(),
+      PlatformType::Baidu => self.trending_baidu_with_req(req),
+      PlatformType::Douban => self.trending_douban_with_req(req),
+      PlatformType::Kuaishou => self.trending_kuaishou(),
+      PlatformType::Kr36 => self.trending_kr36(),
+      PlatformType::Huxiu => self.trending_huxiu(),
+      PlatformType::Ithome => self.trending_ithome(),
+      PlatformType::Sspai => self.trending_sspai(),
+      PlatformType::Juejin => self.trending_juejin(),
+      PlatformType::V2ex => self.trending_v2ex(),
+      PlatformType::Csdn => self.trending_csdn(),
+      PlatformType::HackerNews => self.trending_hackernews(),
+      PlatformType::Reddit => self.trending_reddit_with_req(req),
+      PlatformType::Github => self.trending_github_with_req(req),
+      PlatformType::Thepaper => self.trending_thepaper(),
+      PlatformType::Ifeng => self.trending_ifeng(),
+      PlatformType::Other(other) => UnsupportedPlatformSnafu { platform: other }.fail(),
+    }
+  }
+
+  pub fn search(&self, platform: &PlatformType, query: &SearchReq) -> Result<SearchesRes> {
+    match platform {
+      PlatformType::Tencent => self.search_tencent(query),
+      PlatformType::Netease => self.search_netease(query),
+      PlatformType::Hupu => self.search_hupu(query),
+      PlatformType::Toutiao => self.search_toutiao(query),
+      PlatformType::Weibo => self.search_weibo(query),
+      PlatformType::Zhihu => self.search_zhihu(query),
+      other => UnsupportedPlatformSnafu {
+        platform: other.to_str(),
+      }
+      .fail(),
+    }
+  }
+
+  /// Blocking counterpart of [`AsyncClient::search_stream`].
+  pub fn search_iter<'a>(
+    &'a self,
+    platform: &'a PlatformType,
+    query: &SearchReq,
+    limit: Option<usize>,
+  ) -> impl Iterator<Item = Result<SearchRes>> + 'a {
+    let mut pager = SearchPager::new(query, limit);
+    std::iter::from_fn(move || {
+      loop {
+        if let Some(item) = pager.next_item() {
+          return Some(item);
+        }
+        let query = pager.next_req()?;
+        let res = self.search(platform, &query);
+        pager.push_page(res);
+      }
+    })
   }
 
   pub fn trending_zhihu(&self) -> Result<TrendingsRes> {
-    crate::zhihu::block_trending(&self.client)
+    self.cached(PlatformType::Zhihu, |headers| {
+      crate::zhihu::block_trending(&self.client, headers)
+    })
+  }
+
+  pub fn search_zhihu(&self, query: &SearchReq) -> Result<SearchesRes> {
+    self.searched(PlatformType::Zhihu, query, |headers| {
+      crate::zhihu::blocking_search(&self.client, headers, query)
+    })
   }
 
   pub fn trending_weibo(&self) -> Result<TrendingsRes> {
-    crate::weibo::block_trending(&self.client)
+    self.trending_weibo_with_req(&TrendingReq::default())
+  }
+
+  /// Weibo boards are `hot`, `realtime`, `entertainment` and `topic`, topics carry
+  /// read and discussion counts. `entertainment` and `topic` need a logged in
+  /// `cookie` header for weibo and fail without one.
+  pub fn trending_weibo_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Weibo, req, |headers| {
+      crate::weibo::block_trending(&self.client, headers, req)
+    })
+  }
+
+  pub fn search_weibo(&self, query: &SearchReq) -> Result<SearchesRes> {
+    self.searched(PlatformType::Weibo, query, |headers| {
+      crate::weibo::blocking_search(&self.client, headers, query)
+    })
   }
 
   pub fn trending_toutiao(&self) -> Result<TrendingsRes> {
-    crate::toutiao::blocking_trending(&self.client)
+    self.cached(PlatformType::Toutiao, |headers| {
+      crate::toutiao::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn search_toutiao(&self, query: &SearchReq) -> Result<SearchesRes> {
+    self.searched(PlatformType::Toutiao, query, |headers| {
+      crate::toutiao::blocking_search(&self.client, headers, query)
+    })
   }
 
   pub fn trending_tencent(&self) -> Result<TrendingsRes> {
-    crate::tencent::blocking_trending(&self.client)
+    self.trending_tencent_with_req(&TrendingReq::default())
+  }
+
+  /// Hot ranking of tencent news, the board selects another rank list, see
+  /// [`TrendingReq::with_offset`] and [`TrendingReq::with_size`] for paging.
+  pub fn trending_tencent_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Tencent, req, |headers| {
+      crate::tencent::blocking_trending(&self.client, headers, req)
+    })
   }
 
   pub fn search_tencent(&self, query: &SearchReq) -> Result<SearchesRes> {
-    crate::tencent::blocking_search(&self.client, query)
+    self.searched(PlatformType::Tencent, query, |headers| {
+      crate::tencent::blocking_search(&self.client, headers, query)
+    })
   }
 
   pub fn trending_tieba(&self) -> Result<TrendingsRes> {
-    crate::tieba::blocking_trending(&self.client)
+    self.trending_tieba_with_req(&TrendingReq::default())
+  }
+
+  /// Hot topics of tieba, a board switches to the hot threads of the forum with
+  /// that name, see [`TrendingReq::with_board`].
+  pub fn trending_tieba_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Tieba, req, |headers| {
+      crate::tieba::blocking_trending(&self.client, headers, req)
+    })
+  }
+
+  /// Description, discussion count and top posts of a tieba hot topic.
+  pub fn tieba_topic(&self, topic_id: u64) -> Result<TopicRes> {
+    self.retry(&PlatformType::Tieba, |headers| {
+      crate::tieba::blocking_topic(&self.client, headers, topic_id)
+    })
   }
 
   pub fn trending_netease(&self) -> Result<TrendingsRes> {
-    crate::netease::blocking_trending(&self.client)
+    self.cached(PlatformType::Netease, |headers| {
+      crate::netease::blocking_trending(&self.client, headers)
+    })
   }
 
   pub fn search_netease(&self, query: &SearchReq) -> Result<SearchesRes> {
-    crate::netease::blocking_search(&self.client, query)
+    self.searched(PlatformType::Netease, query, |headers| {
+      crate::netease::blocking_search(&self.client, headers, query)
+    })
   }
 
   pub fn trending_hupu(&self) -> Result<TrendingsRes> {
-    crate::hupu::blocking_trending(&self.client)
+    self.trending_hupu_with_req(&TrendingReq::default())
+  }
+
+  /// Hot threads of a hupu board, see [`TrendingReq::with_board`], defaults to `bxj`,
+  /// the `page` param selects the page.
+  pub fn trending_hupu_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Hupu, req, |headers| {
+      crate::hupu::blocking_trending(&self.client, headers, req)
+    })
+  }
+
+  pub fn search_hupu(&self, query: &SearchReq) -> Result<SearchesRes> {
+    self.searched(PlatformType::Hupu, query, |headers| {
+      crate::hupu::blocking_search(&self.client, headers, query)
+    })
+  }
+
+  pub fn trending_bilibili(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Bilibili, |headers| {
+      crate::bilibili::blocking_trending(&self.client, headers)
+    })
+  }
+
+  /// Video ranking of a bilibili category, `rid` 0 means all categories.
+  pub fn ranking_bilibili(&self, rid: u32) -> Result<TrendingsRes> {
+    self.retry(&PlatformType::Bilibili, |headers| {
+      crate::bilibili::blocking_ranking(&self.client, headers, rid)
+    })
+  }
+
+  /// Douyin may answer with an empty list without a `cookie`, see
+  /// [`ClientOptions::with_platform_header`].
+  pub fn trending_douyin(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Douyin, |headers| {
+      crate::douyin::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_baidu(&self) -> Result<TrendingsRes> {
+    self.trending_baidu_with_req(&TrendingReq::default())
+  }
+
+  /// Hot search of a baidu board, see [`TrendingReq::with_board`], defaults to `realtime`.
+  pub fn trending_baidu_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Baidu, req, |headers| {
+      crate::baidu::blocking_trending(&self.client, headers, req)
+    })
+  }
+
+  pub fn trending_douban(&self) -> Result<TrendingsRes> {
+    self.trending_douban_with_req(&TrendingReq::default())
+  }
+
+  /// Hot group topics or weekly charts of douban, boards are `group`, `movie` and `book`.
+  pub fn trending_douban_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Douban, req, |headers| {
+      crate::douban::blocking_trending(&self.client, headers, req)
+    })
+  }
+
+  /// Hot board of kuaishou ordered by rank, see [`crate::common::TrendingRes::rank`].
+  pub fn trending_kuaishou(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Kuaishou, |headers| {
+      crate::kuaishou::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_kr36(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Kr36, |headers| {
+      crate::kr36::blocking_trending(&self.client, headers)
+    })
+  }
+
+  /// Latest articles of huxiu, huxiu publishes no hot list.
+  pub fn trending_huxiu(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Huxiu, |headers| {
+      crate::huxiu::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_ithome(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Ithome, |headers| {
+      crate::ithome::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_sspai(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Sspai, |headers| {
+      crate::sspai::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_juejin(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Juejin, |headers| {
+      crate::juejin::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_v2ex(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::V2ex, |headers| {
+      crate::v2ex::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_csdn(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Csdn, |headers| {
+      crate::csdn::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_hackernews(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::HackerNews, |headers| {
+      crate::hackernews::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_reddit(&self) -> Result<TrendingsRes> {
+    self.trending_reddit_with_req(&TrendingReq::default())
+  }
+
+  /// Hot posts of a subreddit taken from the board, defaults to `popular`.
+  pub fn trending_reddit_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Reddit, req, |headers| {
+      crate::reddit::blocking_trending(&self.client, headers, req)
+    })
+  }
+
+  pub fn trending_github(&self) -> Result<TrendingsRes> {
+    self.trending_github_with_req(&TrendingReq::default())
+  }
+
+  /// Trending repositories, filtered by the `language` and `since` params.
+  pub fn trending_github_with_req(&self, req: &TrendingReq) -> Result<TrendingsRes> {
+    self.cached_with_req(PlatformType::Github, req, |headers| {
+      crate::github::blocking_trending(&self.client, headers, req)
+    })
+  }
+
+  pub fn trending_thepaper(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Thepaper, |headers| {
+      crate::thepaper::blocking_trending(&self.client, headers)
+    })
+  }
+
+  pub fn trending_ifeng(&self) -> Result<TrendingsRes> {
+    self.cached(PlatformType::Ifeng, |headers| {
+      crate::ifeng::blocking_trending(&self.client, headers)
+    })
+  }
+
+  fn retry<T>(
+    &self,
+    platform: &PlatformType,
+    f: impl Fn(Option<HeaderMap>) -> Result<T>,
+  ) -> Result<T> {
+    let mut attempt = 0;
+    loop {
+      match f(self.platform_headers.get(platform).cloned()) {
+        Err(err) if attempt < self.retries && err.is_retryable() => {
+          std::thread::sleep(retry_delay(&err, attempt));
+          attempt += 1;
+        }
+        res => return res,
+      }
+    }
+  }
+
+  fn searched(
+    &self,
+    platform: PlatformType,
+    req: &SearchReq,
+    f: impl Fn(Option<HeaderMap>) -> Result<SearchesRes>,
+  ) -> Result<SearchesRes> {
+    self.retry(&platform, f).map(|r| r.filtered(req))
+  }
+
+  fn cached(
+    &self,
+    platform: PlatformType,
+    f: impl Fn(Option<HeaderMap>) -> Result<TrendingsRes>,
+  ) -> Result<TrendingsRes> {
+    self.cached_with_req(platform, &TrendingReq::default(), f)
+  }
+
+  fn cached_with_req(
+    &self,
+    platform: PlatformType,
+    req: &TrendingReq,
+    f: impl Fn(Option<HeaderMap>) -> Result<TrendingsRes>,
+  ) -> Result<TrendingsRes> {
+    if let Some(res) = self.cache.get(&platform, req) {
+      return Ok(res);
+    }
+    let res = self.retry(&platform, f)?;
+    self.cache.put(platform, req, &res);
+    Ok(res)
+  }
+}
+
+#[cfg(feature = "blocking")]
+impl Default for BlockClient {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Delay before the first retry, doubled for every further one.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound of a retry delay, including the ones asked by `Retry-After`.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Delay before retrying `err` for the `attempt`th time counted from 0, the
+/// `Retry-After` of a 429 or 5xx response wins over the exponential backoff.
+fn retry_delay(err: &TrendingError, attempt: u32) -> Duration {
+  err
+    .retry_after()
+    .unwrap_or_else(|| RETRY_BASE_DELAY.saturating_mul(1 << attempt.min(16)))
+    .min(RETRY_MAX_DELAY)
+}
+
+struct TrendingCache {
+  ttl: Option<Duration>,
+  entries: Mutex<HashMap<(PlatformType, TrendingReq), (Instant, TrendingsRes)>>,
+}
+
+impl TrendingCache {
+  fn new(ttl: Option<Duration>) -> Self {
+    Self {
+      ttl,
+      entries: Mutex::new(HashMap::new()),
+    }
+  }
+
+  fn get(&self, platform: &PlatformType, req: &TrendingReq) -> Option<TrendingsRes> {
+    let ttl = self.ttl?;
+    let entries = self.entries.lock().ok()?;
+    entries
+      .get(&(platform.clone(), req.clone()))
+      .filter(|(time, _)| time.elapsed() < ttl)
+      .map(|(_, res)| res.clone())
+  }
+
+  fn put(&self, platform: PlatformType, req: &TrendingReq, res: &TrendingsRes) {
+    if self.ttl.is_none() {
+      return;
+    }
+    if let Ok(mut entries) = self.entries.lock() {
+      entries.insert((platform, req.clone()), (Instant::now(), res.clone()));
+    }
   }
 }
 
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
   pub headers: HeaderMap,
+  /// Timeout of a request, on wasm32 the client drops requests that outlive it.
   pub timeout: Option<Duration>,
+  #[cfg(not(target_arch = "wasm32"))]
   pub proxy: Option<Proxy>,
+  /// Times a request is retried after a timeout, connect error or 5xx/429 response,
+  /// waiting with an exponential backoff or as long as `Retry-After` asks.
+  pub retries: Option<u32>,
+  /// How long trending results are reused before the platform is queried again.
+  pub cache_ttl: Option<Duration>,
+  /// Extra headers sent only to one platform, e.g. cookies required by douyin.
+  pub platform_headers: HashMap<PlatformType, HeaderMap>,
 }
 
 impl ClientOptions {
@@ -151,7 +1099,11 @@ impl ClientOptions {
     ClientOptions {
       headers: HeaderMap::new(),
       timeout: None,
+      #[cfg(not(target_arch = "wasm32"))]
       proxy: None,
+      retries: None,
+      cache_ttl: None,
+      platform_headers: HashMap::new(),
     }
   }
 
@@ -165,6 +1117,30 @@ impl ClientOptions {
     self
   }
 
+  pub fn with_platform_headers(mut self, platform: PlatformType, headers: HeaderMap) -> Self {
+    self
+      .platform_headers
+      .entry(platform)
+      .or_default()
+      .extend(headers);
+    self
+  }
+
+  pub fn with_platform_header(
+    mut self,
+    platform: PlatformType,
+    key: HeaderName,
+    value: HeaderValue,
+  ) -> Self {
+    self
+      .platform_headers
+      .entry(platform)
+      .or_default()
+      .insert(key, value);
+    self
+  }
+
+  #[cfg(not(target_arch = "wasm32"))]
   pub fn with_proxy(mut self, proxy: Proxy) -> Self {
     self.proxy = Some(proxy);
     self
@@ -175,7 +1151,86 @@ impl ClientOptions {
     self
   }
 
+  pub fn with_retries(mut self, retries: u32) -> Self {
+    self.retries = Some(retries);
+    self
+  }
+
+  pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+    self.cache_ttl = Some(ttl);
+    self
+  }
+
   pub fn contains_header(&self, key: impl AsHeaderName) -> bool {
     self.headers.contains_key(key)
   }
 }
+
+impl Default for ClientOptions {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn search_res(url: &str, time: u64) -> SearchRes {
+    SearchRes {
+      title: url.to_string(),
+      url: url.to_string(),
+      time: Some(time),
+      medias: None,
+      desc: None,
+      author: None,
+      likes: None,
+      comments: None,
+      kind: None,
+      category: None,
+    }
+  }
+
+  fn page(result: Vec<SearchRes>, next: Option<PageParam>) -> Result<SearchesRes> {
+    Ok(SearchesRes {
+      platform: PlatformType::Zhihu,
+      result,
+      ignored: Vec::new(),
+      next,
+    })
+  }
+
+  #[test]
+  fn pager_walks_past_pages_emptied_by_time_window() {
+    let mut pager = SearchPager::new(&SearchReq::new("rust").with_since(150), None);
+    let req = pager.next_req().unwrap();
+    assert_eq!(req.page, Some(PageParam::First));
+    assert_eq!(req.since, None);
+
+    // every result of the first page is older than the window
+    pager.push_page(page(
+      vec![search_res("a", 100), search_res("b", 110)],
+      Some(PageParam::Other(1)),
+    ));
+    assert!(pager.next_item().is_none());
+    let req = pager.next_req().unwrap();
+    assert_eq!(req.page, Some(PageParam::Other(1)));
+
+    pager.push_page(page(vec![search_res("c", 200)], None));
+    assert_eq!(pager.next_item().unwrap().unwrap().url, "c");
+    assert!(pager.next_item().is_none());
+    assert!(pager.next_req().is_none());
+  }
+
+  #[test]
+  fn pager_stops_on_repeated_page() {
+    let mut pager = SearchPager::new(&SearchReq::new("rust"), None);
+    pager.next_req().unwrap();
+    pager.push_page(page(vec![search_res("a", 100)], Some(PageParam::Other(1))));
+    assert_eq!(pager.next_item().unwrap().unwrap().url, "a");
+    pager.next_req().unwrap();
+    pager.push_page(page(vec![search_res("a", 100)], Some(PageParam::Other(2))));
+    assert!(pager.next_item().is_none());
+    assert!(pager.next_req().is_none());
+  }
+}
@@ -1,14 +1,17 @@
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display, time::Duration};
 
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
-use reqwest::{Client as AsyncClient, Method, header::HeaderMap};
+use reqwest::{
+  Client as AsyncClient, Method,
+  header::{HeaderMap, RETRY_AFTER},
+};
 use serde::{Deserialize, Serialize};
-use snafu::ResultExt;
+use snafu::{OptionExt, ResultExt};
 
-use crate::errors::{ReqwestClientSnafu, Result};
+use crate::errors::{HttpStatusSnafu, InvalidParamSnafu, ReqwestClientSnafu, Result};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PlatformType {
   #[serde(rename = "zhihu")]
   Zhihu,
@@ -31,6 +34,57 @@ pub enum PlatformType {
   #[serde(rename = "hupu")]
   Hupu,
 
+  #[serde(rename = "bilibili")]
+  Bilibili,
+
+  #[serde(rename = "douyin")]
+  Douyin,
+
+  #[serde(rename = "baidu")]
+  Baidu,
+
+  #[serde(rename = "douban")]
+  Douban,
+
+  #[serde(rename = "kuaishou")]
+  Kuaishou,
+
+  #[serde(rename = "36kr")]
+  Kr36,
+
+  #[serde(rename = "huxiu")]
+  Huxiu,
+
+  #[serde(rename = "ithome")]
+  Ithome,
+
+  #[serde(rename = "sspai")]
+  Sspai,
+
+  #[serde(rename = "juejin")]
+  Juejin,
+
+  #[serde(rename = "v2ex")]
+  V2ex,
+
+  #[serde(rename = "csdn")]
+  Csdn,
+
+  #[serde(rename = "hackernews")]
+  HackerNews,
+
+  #[serde(rename = "reddit")]
+  Reddit,
+
+  #[serde(rename = "github")]
+  Github,
+
+  #[serde(rename = "thepaper")]
+  Thepaper,
+
+  #[serde(rename = "ifeng")]
+  Ifeng,
+
   #[serde(untagged)]
   Other(String),
 }
@@ -45,9 +99,73 @@ impl PlatformType {
       PlatformType::Tieba => "tieba",
       PlatformType::Netease => "netease",
       PlatformType::Hupu => "hupu",
+      PlatformType::Bilibili => "bilibili",
+      PlatformType::Douyin => "douyin",
+      PlatformType::Baidu => "baidu",
+      PlatformType::Douban => "douban",
+      PlatformType::Kuaishou => "kuaishou",
+      PlatformType::Kr36 => "36kr",
+      PlatformType::Huxiu => "huxiu",
+      PlatformType::Ithome => "ithome",
+      PlatformType::Sspai => "sspai",
+      PlatformType::Juejin => "juejin",
+      PlatformType::V2ex => "v2ex",
+      PlatformType::Csdn => "csdn",
+      PlatformType::HackerNews => "hackernews",
+      PlatformType::Reddit => "reddit",
+      PlatformType::Github => "github",
+      PlatformType::Thepaper => "thepaper",
+      PlatformType::Ifeng => "ifeng",
       PlatformType::Other(other) => other.as_str(),
     }
   }
+
+  /// Filters of [`SearchReq`] the search of this platform honors natively. The
+  /// time window is not among them as no platform filters by time upstream, it
+  /// is applied by the client instead, see [`SearchFilter::is_client_side`].
+  pub fn search_filters(&self) -> &'static [SearchFilter] {
+    match self {
+      PlatformType::Zhihu => crate::zhihu::SEARCH_FILTERS,
+      PlatformType::Weibo => crate::weibo::SEARCH_FILTERS,
+      PlatformType::Toutiao => crate::toutiao::SEARCH_FILTERS,
+      PlatformType::Tencent => crate::tencent::SEARCH_FILTERS,
+      PlatformType::Netease => crate::netease::SEARCH_FILTERS,
+      PlatformType::Hupu => crate::hupu::SEARCH_FILTERS,
+      _ => &[],
+    }
+  }
+}
+
+impl From<&str> for PlatformType {
+  fn from(value: &str) -> Self {
+    match value {
+      "zhihu" => PlatformType::Zhihu,
+      "weibo" => PlatformType::Weibo,
+      "toutiao" => PlatformType::Toutiao,
+      "tencent" => PlatformType::Tencent,
+      "tieba" => PlatformType::Tieba,
+      "netease" => PlatformType::Netease,
+      "hupu" => PlatformType::Hupu,
+      "bilibili" => PlatformType::Bilibili,
+      "douyin" => PlatformType::Douyin,
+      "baidu" => PlatformType::Baidu,
+      "douban" => PlatformType::Douban,
+      "kuaishou" => PlatformType::Kuaishou,
+      "36kr" => PlatformType::Kr36,
+      "huxiu" => PlatformType::Huxiu,
+      "ithome" => PlatformType::Ithome,
+      "sspai" => PlatformType::Sspai,
+      "juejin" => PlatformType::Juejin,
+      "v2ex" => PlatformType::V2ex,
+      "csdn" => PlatformType::Csdn,
+      "hackernews" => PlatformType::HackerNews,
+      "reddit" => PlatformType::Reddit,
+      "github" => PlatformType::Github,
+      "thepaper" => PlatformType::Thepaper,
+      "ifeng" => PlatformType::Ifeng,
+      other => PlatformType::Other(other.to_string()),
+    }
+  }
 }
 
 impl Display for PlatformType {
@@ -65,7 +183,39 @@ pub struct TrendingsRes {
   pub result: Vec<TrendingRes>,
 }
 
+/// Details of a single hot topic together with its top posts.
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TopicRes {
+  #[serde(rename = "platform")]
+  pub platform: PlatformType,
+
+  #[serde(rename = "title")]
+  pub title: String,
+
+  #[serde(rename = "url")]
+  pub url: String,
+
+  #[serde(rename = "desc", skip_serializing_if = "Option::is_none", default)]
+  pub desc: Option<String>,
+
+  #[serde(
+    rename = "discussions",
+    skip_serializing_if = "Option::is_none",
+    default
+  )]
+  pub discussions: Option<u64>,
+
+  #[serde(rename = "medias", skip_serializing_if = "Option::is_none", default)]
+  pub medias: Option<Vec<MediaData>>,
+
+  #[serde(rename = "posts", skip_serializing_if = "Vec::is_empty", default)]
+  pub posts: Vec<TrendingRes>,
+}
+
+/// One entry of a hot list, build it with [`TrendingRes::new`] and the `with_*`
+/// setters as fields may be added over time.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct TrendingRes {
   #[serde(rename = "title")]
   pub title: String,
@@ -75,6 +225,93 @@ pub struct TrendingRes {
 
   #[serde(rename = "trend")]
   pub trend: Option<String>,
+
+  #[serde(rename = "medias", skip_serializing_if = "Option::is_none", default)]
+  pub medias: Option<Vec<MediaData>>,
+
+  /// Badge of an entry such as `new`, `hot` or `boiling`.
+  #[serde(rename = "label", skip_serializing_if = "Option::is_none", default)]
+  pub label: Option<String>,
+
+  #[serde(rename = "desc", skip_serializing_if = "Option::is_none", default)]
+  pub desc: Option<String>,
+
+  #[serde(rename = "author", skip_serializing_if = "Option::is_none", default)]
+  pub author: Option<String>,
+
+  /// Publish time as unix timestamp in seconds.
+  #[serde(rename = "time", skip_serializing_if = "Option::is_none", default)]
+  pub time: Option<u64>,
+
+  #[serde(rename = "reads", skip_serializing_if = "Option::is_none", default)]
+  pub reads: Option<u64>,
+
+  #[serde(rename = "comments", skip_serializing_if = "Option::is_none", default)]
+  pub comments: Option<u64>,
+
+  /// Position in the hot list as given by the platform, counted from 1.
+  #[serde(rename = "rank", skip_serializing_if = "Option::is_none", default)]
+  pub rank: Option<u32>,
+
+  /// Programming language of a repository.
+  #[serde(rename = "language", skip_serializing_if = "Option::is_none", default)]
+  pub language: Option<String>,
+}
+
+impl TrendingRes {
+  pub fn new(title: impl Into<String>, url: impl Into<String>, trend: Option<String>) -> Self {
+    Self {
+      title: title.into(),
+      url: url.into(),
+      trend,
+      ..Default::default()
+    }
+  }
+
+  pub fn with_medias(mut self, medias: Vec<MediaData>) -> Self {
+    self.medias = Some(medias);
+    self
+  }
+
+  pub fn with_label(mut self, label: impl Into<String>) -> Self {
+    self.label = Some(label.into());
+    self
+  }
+
+  pub fn with_desc(mut self, desc: impl Into<String>) -> Self {
+    self.desc = Some(desc.into());
+    self
+  }
+
+  pub fn with_author(mut self, author: impl Into<String>) -> Self {
+    self.author = Some(author.into());
+    self
+  }
+
+  pub fn with_time(mut self, time: u64) -> Self {
+    self.time = Some(time);
+    self
+  }
+
+  pub fn with_reads(mut self, reads: u64) -> Self {
+    self.reads = Some(reads);
+    self
+  }
+
+  pub fn with_comments(mut self, comments: u64) -> Self {
+    self.comments = Some(comments);
+    self
+  }
+
+  pub fn with_rank(mut self, rank: u32) -> Self {
+    self.rank = Some(rank);
+    self
+  }
+
+  pub fn with_language(mut self, language: impl Into<String>) -> Self {
+    self.language = Some(language.into());
+    self
+  }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -84,12 +321,181 @@ pub struct SearchesRes {
 
   #[serde(rename = "searches", skip_serializing_if = "Vec::is_empty", default)]
   pub result: Vec<SearchRes>,
+
+  /// Filters of the request the platform does not support and were ignored.
+  #[serde(rename = "ignored", skip_serializing_if = "Vec::is_empty", default)]
+  pub ignored: Vec<SearchFilter>,
+
+  /// Page following this one, `None` once the results run out.
+  #[serde(rename = "next", skip_serializing_if = "Option::is_none", default)]
+  pub next: Option<PageParam>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl SearchesRes {
+  /// Sets the next page for platforms paging by page number, a page shorter
+  /// than `size` is the last one.
+  pub(crate) fn with_next_page(mut self, req: &SearchReq, size: u32) -> Result<Self> {
+    let page = req
+      .page
+      .as_ref()
+      .map_or(Ok(0), |p| p.number(self.platform.clone(), size))?;
+    if !self.result.is_empty() && self.result.len() >= size as usize {
+      // the last page has no successor
+      self.next = page.checked_add(1).map(PageParam::Other);
+    }
+    Ok(self)
+  }
+
+  /// Drops results outside the time window of `req` and reports the filters of
+  /// `req` this platform ignored, including the ones the platform reported for
+  /// this request only. Results without a publish time are kept as
+  /// they can not be placed in the window.
+  pub(crate) fn filtered(mut self, req: &SearchReq) -> Self {
+    let supported = self.platform.search_filters();
+    // keep the filters the platform already reported for this request
+    let ignored: Vec<_> = req
+      .filters()
+      .into_iter()
+      .filter(|f| !f.is_client_side() && (!supported.contains(f) || self.ignored.contains(f)))
+      .collect();
+    self.ignored = ignored;
+    let (since, until) = (req.since, req.until);
+    self.result.retain(|r| match r.time {
+      Some(time) => since.is_none_or(|s| time >= s) && until.is_none_or(|u| time <= u),
+      None => true,
+    });
+    self
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchType {
+  #[serde(rename = "news")]
+  News,
+
+  #[serde(rename = "video")]
+  Video,
+
+  #[serde(rename = "account")]
+  Account,
+}
+
+impl SearchType {
+  pub fn as_str(&self) -> &str {
+    match self {
+      SearchType::News => "news",
+      SearchType::Video => "video",
+      SearchType::Account => "account",
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchSort {
+  #[serde(rename = "relevance")]
+  Relevance,
+
+  #[serde(rename = "newest")]
+  Newest,
+
+  #[serde(rename = "hottest")]
+  Hottest,
+}
+
+impl SearchSort {
+  pub fn as_str(&self) -> &str {
+    match self {
+      SearchSort::Relevance => "relevance",
+      SearchSort::Newest => "newest",
+      SearchSort::Hottest => "hottest",
+    }
+  }
+}
+
+/// Optional filters of [`SearchReq`], see [`PlatformType::search_filters`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchFilter {
+  #[serde(rename = "since")]
+  Since,
+
+  #[serde(rename = "until")]
+  Until,
+
+  #[serde(rename = "sort")]
+  Sort,
+
+  #[serde(rename = "kind")]
+  Kind,
+
+  #[serde(rename = "size")]
+  Size,
+}
+
+impl SearchFilter {
+  pub fn as_str(&self) -> &str {
+    match self {
+      SearchFilter::Since => "since",
+      SearchFilter::Until => "until",
+      SearchFilter::Sort => "sort",
+      SearchFilter::Kind => "kind",
+      SearchFilter::Size => "size",
+    }
+  }
+
+  /// Whether the client applies the filter to the fetched results rather than
+  /// sending it to the platform, this is the case of the time window.
+  pub fn is_client_side(&self) -> bool {
+    matches!(self, SearchFilter::Since | SearchFilter::Until)
+  }
+}
+
+/// Position of a search page, whatever the platform pages by, the `next` of
+/// [`SearchesRes`] can be fed back into [`SearchReq::with_page`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum PageParam {
   First,
+  /// Page number counted from 0.
   Other(u32),
+  /// Number of results to skip.
+  Offset(u32),
+  /// Opaque cursor handed out by the platform.
+  Cursor(String),
+}
+
+impl PageParam {
+  /// Page number counted from 0 for platforms paging by number, a cursor must
+  /// hold a page number.
+  pub(crate) fn number(&self, platform: PlatformType, size: u32) -> Result<u32> {
+    match self {
+      PageParam::First => Ok(0),
+      PageParam::Other(page) => Ok(*page),
+      PageParam::Offset(offset) => Ok(offset / size.max(1)),
+      PageParam::Cursor(cursor) => Self::parse_cursor(platform, cursor),
+    }
+  }
+
+  /// Number of results to skip for platforms paging by offset, a cursor must
+  /// hold an offset.
+  pub(crate) fn offset(&self, platform: PlatformType, size: u32) -> Result<u32> {
+    match self {
+      PageParam::First => Ok(0),
+      PageParam::Other(page) => page.checked_mul(size).context(InvalidParamSnafu {
+        platform: platform.to_str(),
+        param: "page",
+        value: page.to_string(),
+      }),
+      PageParam::Offset(offset) => Ok(*offset),
+      PageParam::Cursor(cursor) => Self::parse_cursor(platform, cursor),
+    }
+  }
+
+  fn parse_cursor(platform: PlatformType, cursor: &str) -> Result<u32> {
+    cursor.parse().ok().context(InvalidParamSnafu {
+      platform: platform.to_str(),
+      param: "page",
+      value: cursor,
+    })
+  }
 }
 
 impl From<u32> for PageParam {
@@ -98,6 +504,72 @@ impl From<u32> for PageParam {
   }
 }
 
+/// Parameters of a trending query, e.g. the board of platforms with several hot lists.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TrendingReq {
+  #[serde(rename = "board", skip_serializing_if = "Option::is_none", default)]
+  pub board: Option<String>,
+
+  /// Platform specific parameters, e.g. `language` and `since` of github.
+  #[serde(rename = "params", skip_serializing_if = "BTreeMap::is_empty", default)]
+  pub params: BTreeMap<String, String>,
+}
+
+impl TrendingReq {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_board(mut self, board: impl Into<String>) -> Self {
+    self.board = Some(board.into());
+    self
+  }
+
+  pub fn with_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    self.params.insert(key.into(), value.into());
+    self
+  }
+
+  /// Skips the first `offset` items, for platforms paging their hot list.
+  pub fn with_offset(self, offset: u32) -> Self {
+    self.with_param("offset", offset.to_string())
+  }
+
+  /// Number of items per page, for platforms paging their hot list.
+  pub fn with_size(self, size: u32) -> Self {
+    self.with_param("size", size.to_string())
+  }
+
+  pub(crate) fn param(&self, key: &str) -> Option<&str> {
+    self.params.get(key).map(String::as_str)
+  }
+
+  pub(crate) fn param_u32(&self, platform: PlatformType, key: &str) -> Result<Option<u32>> {
+    self
+      .param(key)
+      .map(|value| {
+        value.parse().ok().context(InvalidParamSnafu {
+          platform: platform.to_str(),
+          param: key,
+          value,
+        })
+      })
+      .transpose()
+  }
+}
+
+impl From<&str> for TrendingReq {
+  fn from(value: &str) -> Self {
+    Self::new().with_board(value)
+  }
+}
+
+impl From<String> for TrendingReq {
+  fn from(value: String) -> Self {
+    Self::new().with_board(value)
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SearchReq {
   #[serde(rename = "keyword")]
@@ -108,6 +580,23 @@ pub struct SearchReq {
 
   #[serde(rename = "size")]
   pub size: Option<u32>,
+
+  /// Content type filter, platforms without sections ignore it.
+  #[serde(rename = "kind", skip_serializing_if = "Option::is_none", default)]
+  pub kind: Option<SearchType>,
+
+  /// Start of the time window as unix timestamp in seconds, applied by the
+  /// client to the publish time of the results, undated results are kept.
+  #[serde(rename = "since", skip_serializing_if = "Option::is_none", default)]
+  pub since: Option<u64>,
+
+  /// End of the time window as unix timestamp in seconds, applied like
+  /// [`SearchReq::since`].
+  #[serde(rename = "until", skip_serializing_if = "Option::is_none", default)]
+  pub until: Option<u64>,
+
+  #[serde(rename = "sort", skip_serializing_if = "Option::is_none", default)]
+  pub sort: Option<SearchSort>,
 }
 
 impl SearchReq {
@@ -116,6 +605,10 @@ impl SearchReq {
       keyword: keyword.into(),
       page: None,
       size: None,
+      kind: None,
+      since: None,
+      until: None,
+      sort: None,
     }
   }
 
@@ -128,6 +621,40 @@ impl SearchReq {
     self.size = Some(size);
     self
   }
+
+  pub fn with_kind(mut self, kind: SearchType) -> Self {
+    self.kind = Some(kind);
+    self
+  }
+
+  pub fn with_since(mut self, since: u64) -> Self {
+    self.since = Some(since);
+    self
+  }
+
+  pub fn with_until(mut self, until: u64) -> Self {
+    self.until = Some(until);
+    self
+  }
+
+  pub fn with_sort(mut self, sort: SearchSort) -> Self {
+    self.sort = Some(sort);
+    self
+  }
+
+  /// Filters set on this request.
+  pub fn filters(&self) -> Vec<SearchFilter> {
+    [
+      (self.since.is_some(), SearchFilter::Since),
+      (self.until.is_some(), SearchFilter::Until),
+      (self.sort.is_some(), SearchFilter::Sort),
+      (self.kind.is_some(), SearchFilter::Kind),
+      (self.size.is_some(), SearchFilter::Size),
+    ]
+    .into_iter()
+    .filter_map(|(set, filter)| set.then_some(filter))
+    .collect()
+  }
 }
 
 impl From<&str> for SearchReq {
@@ -179,6 +706,27 @@ pub struct SearchRes {
 
   #[serde(rename = "medias", skip_serializing_if = "Option::is_none")]
   pub medias: Option<Vec<MediaData>>,
+
+  #[serde(rename = "desc", skip_serializing_if = "Option::is_none", default)]
+  pub desc: Option<String>,
+
+  #[serde(rename = "author", skip_serializing_if = "Option::is_none", default)]
+  pub author: Option<String>,
+
+  #[serde(rename = "likes", skip_serializing_if = "Option::is_none", default)]
+  pub likes: Option<u64>,
+
+  #[serde(rename = "comments", skip_serializing_if = "Option::is_none", default)]
+  pub comments: Option<u64>,
+
+  /// Section of the platform the result came from.
+  #[serde(rename = "kind", skip_serializing_if = "Option::is_none", default)]
+  pub kind: Option<SearchType>,
+
+  /// Kind of the result as named by the platform, finer than [`SearchRes::kind`],
+  /// e.g. `answer`, `article`, `question` or `zvideo` on zhihu.
+  #[serde(rename = "category", skip_serializing_if = "Option::is_none", default)]
+  pub category: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -224,7 +772,7 @@ impl MediaData {
   }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum MediaType {
   #[serde(rename = "video")]
   Video,
@@ -242,7 +790,7 @@ pub enum MediaType {
 impl MediaType {
   pub fn as_str(&self) -> &str {
     match self {
-      MediaType::Video => "viode",
+      MediaType::Video => "video",
       MediaType::Audio => "audio",
       MediaType::Image => "image",
       MediaType::Other(other) => other.as_str(),
@@ -251,13 +799,78 @@ impl MediaType {
 }
 
 pub(crate) fn not_empty_str(text: Option<String>) -> Option<String> {
-  return if let Some(s) = &text
+  if let Some(s) = &text
     && !s.is_empty()
   {
     text
   } else {
     None
-  };
+  }
+}
+
+/// Deserializes a count that some platforms send as a number and others as a
+/// string, e.g. `"1234"`, anything that is not a plain number becomes `None`.
+pub(crate) fn de_count<'de, D: serde::Deserializer<'de>>(
+  deserializer: D,
+) -> std::result::Result<Option<u64>, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum Count {
+    Number(u64),
+    Text(String),
+  }
+  Ok(
+    match Option::<Count>::deserialize(deserializer).unwrap_or(None) {
+      Some(Count::Number(n)) => Some(n),
+      Some(Count::Text(t)) => t.trim().parse().ok(),
+      None => None,
+    },
+  )
+}
+
+/// Removes html tags such as the `<em>` around keywords and decodes the
+/// common entities, used for titles and excerpts of search results.
+pub(crate) fn strip_html(text: &str) -> String {
+  let mut stripped = String::with_capacity(text.len());
+  let mut in_tag = false;
+  for c in text.chars() {
+    match c {
+      '<' => in_tag = true,
+      '>' if in_tag => in_tag = false,
+      c if !in_tag => stripped.push(c),
+      _ => {}
+    }
+  }
+  stripped
+    .replace("&nbsp;", " ")
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&#39;", "'")
+    .replace("&amp;", "&")
+}
+
+/// Parses `YYYY-MM-DD HH:MM:SS` (or with a `T` separator) in China Standard
+/// Time into a unix timestamp in seconds, anything after the seconds is ignored.
+pub(crate) fn parse_datetime(text: &str) -> Option<u64> {
+  let num = |range: std::ops::Range<usize>| text.get(range)?.parse::<i64>().ok();
+  let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+  let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+  // days from civil, see http://howardhinnant.github.io/date_algorithms.html
+  let y = if month <= 2 { year - 1 } else { year };
+  let era = y.div_euclid(400);
+  let yoe = y - era * 400;
+  let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+  let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+  let days = era * 146097 + doe - 719468;
+  let secs = days * 86400 + hour * 3600 + minute * 60 + second - 8 * 3600;
+  u64::try_from(secs).ok()
+}
+
+/// Delay of a `Retry-After` header, only the seconds form is understood.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+  let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+  value.trim().parse().ok().map(Duration::from_secs)
 }
 
 pub(crate) async fn http_get<
@@ -313,16 +926,41 @@ async fn http_execute<
       HttpBody::Form(form) => req = req.form(form),
     }
   }
-  let res = req
-    .send()
-    .await
-    .context(ReqwestClientSnafu)?
+  let res = req.send().await.context(ReqwestClientSnafu)?;
+  let retry_after = retry_after(res.headers());
+  let res = res
+    .error_for_status()
+    .context(HttpStatusSnafu { retry_after })?
     .json::<R>()
     .await
     .context(ReqwestClientSnafu)?;
   Ok(res)
 }
 
+/// Like [`http_get`], but returns the body as text, e.g. pages to scrape.
+pub(crate) async fn http_get_text<Q: Serialize + ?Sized>(
+  client: &AsyncClient,
+  url: &str,
+  headers: Option<HeaderMap>,
+  queries: Option<&Q>,
+) -> Result<String> {
+  let mut req = client.get(url);
+  if let Some(headers) = headers {
+    req = req.headers(headers);
+  }
+  if let Some(queries) = queries {
+    req = req.query(queries);
+  }
+  let res = req.send().await.context(ReqwestClientSnafu)?;
+  let retry_after = retry_after(res.headers());
+  res
+    .error_for_status()
+    .context(HttpStatusSnafu { retry_after })?
+    .text()
+    .await
+    .context(ReqwestClientSnafu)
+}
+
 #[cfg(feature = "blocking")]
 pub(crate) fn block_http_get<
   Q: Serialize + ?Sized,
@@ -379,14 +1017,39 @@ fn block_http_execute<
       HttpBody::Form(form) => req = req.form(&form),
     }
   }
-  let res = req
-    .send()
-    .context(ReqwestClientSnafu)?
+  let res = req.send().context(ReqwestClientSnafu)?;
+  let retry_after = retry_after(res.headers());
+  let res = res
+    .error_for_status()
+    .context(HttpStatusSnafu { retry_after })?
     .json::<R>()
     .context(ReqwestClientSnafu)?;
   Ok(res)
 }
 
+#[cfg(feature = "blocking")]
+pub(crate) fn block_http_get_text<Q: Serialize + ?Sized>(
+  client: &BlockClient,
+  url: &str,
+  headers: Option<HeaderMap>,
+  queries: Option<&Q>,
+) -> Result<String> {
+  let mut req = client.get(url);
+  if let Some(headers) = headers {
+    req = req.headers(headers);
+  }
+  if let Some(queries) = queries {
+    req = req.query(queries);
+  }
+  let res = req.send().context(ReqwestClientSnafu)?;
+  let retry_after = retry_after(res.headers());
+  res
+    .error_for_status()
+    .context(HttpStatusSnafu { retry_after })?
+    .text()
+    .context(ReqwestClientSnafu)
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 pub(crate) struct EmptyType;
 
@@ -397,7 +1060,6 @@ pub(crate) enum HttpBody<T> {
 }
 
 impl<T> HttpBody<T> {
-  #[allow(dead_code)]
   pub(crate) fn json(json: T) -> HttpBody<T> {
     HttpBody::<T>::Json(json)
   }
@@ -405,3 +1067,132 @@ impl<T> HttpBody<T> {
     HttpBody::Form(form)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::errors::TrendingError;
+
+  #[test]
+  fn strip_html_removes_tags_and_entities() {
+    assert_eq!(strip_html("<em>rust</em> 1.0"), "rust 1.0");
+    assert_eq!(
+      strip_html("a&nbsp;&lt;b&gt; &quot;c&quot; &#39;d&#39; &amp;"),
+      "a <b> \"c\" 'd' &"
+    );
+    // entities are decoded once, `&amp;lt;` stays a literal `&lt;`
+    assert_eq!(strip_html("&amp;lt;"), "&lt;");
+    assert_eq!(strip_html("1 > 0"), "1 > 0");
+  }
+
+  fn search_res(title: &str, time: Option<u64>) -> SearchRes {
+    SearchRes {
+      title: title.to_string(),
+      url: format!("https://example.com/{title}"),
+      time,
+      medias: None,
+      desc: None,
+      author: None,
+      likes: None,
+      comments: None,
+      kind: None,
+      category: None,
+    }
+  }
+
+  #[test]
+  fn filtered_applies_time_window_and_keeps_undated() {
+    let res = SearchesRes {
+      platform: PlatformType::Hupu,
+      result: vec![
+        search_res("old", Some(100)),
+        search_res("new", Some(200)),
+        search_res("undated", None),
+      ],
+      ignored: Vec::new(),
+      next: None,
+    };
+    let req = SearchReq::new("rust")
+      .with_since(150)
+      .with_sort(SearchSort::Newest);
+    let res = res.filtered(&req);
+    let titles: Vec<_> = res.result.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, ["new", "undated"]);
+    // the time window is applied here, only the sort is reported
+    assert_eq!(res.ignored, [SearchFilter::Sort]);
+  }
+
+  #[test]
+  fn page_number_and_offset() {
+    let platform = || PlatformType::Hupu;
+    assert_eq!(PageParam::First.number(platform(), 20).unwrap(), 0);
+    assert_eq!(PageParam::Other(3).number(platform(), 20).unwrap(), 3);
+    assert_eq!(PageParam::Offset(45).number(platform(), 20).unwrap(), 2);
+    assert_eq!(PageParam::Offset(45).number(platform(), 0).unwrap(), 45);
+    assert_eq!(
+      PageParam::Cursor("4".into())
+        .number(platform(), 20)
+        .unwrap(),
+      4
+    );
+
+    assert_eq!(PageParam::First.offset(platform(), 20).unwrap(), 0);
+    assert_eq!(PageParam::Other(3).offset(platform(), 20).unwrap(), 60);
+    assert_eq!(PageParam::Offset(45).offset(platform(), 20).unwrap(), 45);
+    assert_eq!(
+      PageParam::Cursor("40".into())
+        .offset(platform(), 20)
+        .unwrap(),
+      40
+    );
+  }
+
+  #[test]
+  fn page_rejects_bad_cursor_and_overflow() {
+    let err = PageParam::Cursor("abc".into())
+      .number(PlatformType::Hupu, 20)
+      .unwrap_err();
+    assert!(matches!(err, TrendingError::InvalidParam { ref value, .. } if value == "abc"));
+    let err = PageParam::Cursor("abc".into())
+      .offset(PlatformType::Hupu, 20)
+      .unwrap_err();
+    assert!(matches!(err, TrendingError::InvalidParam { .. }));
+    let err = PageParam::Other(u32::MAX)
+      .offset(PlatformType::Hupu, 20)
+      .unwrap_err();
+    assert!(matches!(err, TrendingError::InvalidParam { .. }));
+  }
+
+  fn searches_res(len: usize) -> SearchesRes {
+    SearchesRes {
+      platform: PlatformType::Hupu,
+      result: (0..len as u64)
+        .map(|i| search_res(&i.to_string(), Some(i)))
+        .collect(),
+      ignored: Vec::new(),
+      next: None,
+    }
+  }
+
+  #[test]
+  fn with_next_page_stops_on_short_page() {
+    let req = SearchReq::new("rust").with_page(2);
+    let res = searches_res(3).with_next_page(&req, 3).unwrap();
+    assert_eq!(res.next, Some(PageParam::Other(3)));
+    let res = searches_res(2).with_next_page(&req, 3).unwrap();
+    assert_eq!(res.next, None);
+    let res = searches_res(0).with_next_page(&req, 3).unwrap();
+    assert_eq!(res.next, None);
+
+    let req = SearchReq::new("rust");
+    let res = searches_res(3).with_next_page(&req, 3).unwrap();
+    assert_eq!(res.next, Some(PageParam::Other(1)));
+
+    let req = SearchReq::new("rust").with_page(u32::MAX);
+    let res = searches_res(3).with_next_page(&req, 3).unwrap();
+    assert_eq!(res.next, None);
+
+    let req = SearchReq::new("rust").with_page(PageParam::Cursor("abc".into()));
+    assert!(searches_res(3).with_next_page(&req, 3).is_err());
+  }
+}
@@ -0,0 +1,88 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, de_count, http_get,
+    not_empty_str,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str =
+  "https://blog.csdn.net/phoenix/web/blog/hot-rank?page=0&pageSize=25";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, CsdnRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, CsdnRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CsdnRes {
+  #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
+  data: Vec<CsdnBlog>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CsdnBlog {
+  #[serde(rename = "articleTitle")]
+  title: String,
+
+  #[serde(rename = "articleDetailUrl")]
+  url: String,
+
+  /// Favorites are the likes of csdn, `hotRankScore` is an opaque score.
+  #[serde(rename = "favorCount", deserialize_with = "de_count", default)]
+  favor_count: Option<u64>,
+
+  #[serde(rename = "commentCount", deserialize_with = "de_count", default)]
+  comment_count: Option<u64>,
+
+  #[serde(rename = "viewCount", deserialize_with = "de_count", default)]
+  view_count: Option<u64>,
+
+  #[serde(rename = "nickName", skip_serializing_if = "Option::is_none")]
+  nick_name: Option<String>,
+
+  #[serde(rename = "picList", skip_serializing_if = "Vec::is_empty", default)]
+  pic_list: Vec<String>,
+}
+
+impl From<CsdnBlog> for TrendingRes {
+  fn from(value: CsdnBlog) -> Self {
+    Self {
+      title: value.title,
+      url: value.url,
+      trend: value.favor_count.map(|f| f.to_string()),
+      medias: value
+        .pic_list
+        .into_iter()
+        .next()
+        .map(|p| vec![MediaData::new_image(p)]),
+      author: not_empty_str(value.nick_name),
+      reads: value.view_count,
+      comments: value.comment_count,
+      ..Default::default()
+    }
+  }
+}
+
+impl From<CsdnRes> for TrendingsRes {
+  fn from(value: CsdnRes) -> Self {
+    Self {
+      platform: PlatformType::Csdn,
+      result: value.data.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
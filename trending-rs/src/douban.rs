@@ -0,0 +1,224 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{
+  Client as AsyncClient,
+  header::{HeaderMap, HeaderValue, REFERER},
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingReq, TrendingRes, TrendingsRes, http_get,
+    not_empty_str,
+  },
+  errors::{Result, TrendingError, UnsupportedBoardSnafu},
+};
+
+pub const GROUP_ENDPOINT: &str =
+  "https://m.douban.com/rexxar/api/v2/group/hot_topics?start=0&count=50";
+pub const COLLECTION_ENDPOINT: &str = "https://m.douban.com/rexxar/api/v2/subject_collection";
+
+/// Boards of douban, the first one is used when the request has no board.
+pub const BOARDS: [&str; 3] = ["group", "movie", "book"];
+
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  let headers = Some(with_referer(headers));
+  match DoubanBoard::try_from(req)? {
+    DoubanBoard::Group => {
+      http_get::<EmptyType, EmptyType, DoubanGroupRes>(client, GROUP_ENDPOINT, headers, None, None)
+        .await
+        .map(|r| r.into())
+    }
+    DoubanBoard::Collection(name) => http_get::<EmptyType, EmptyType, DoubanCollectionRes>(
+      client,
+      &collection_url(name),
+      headers,
+      None,
+      None,
+    )
+    .await
+    .map(|r| r.into()),
+  }
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  let headers = Some(with_referer(headers));
+  match DoubanBoard::try_from(req)? {
+    DoubanBoard::Group => block_http_get::<EmptyType, EmptyType, DoubanGroupRes>(
+      client,
+      GROUP_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
+    .map(|r| r.into()),
+    DoubanBoard::Collection(name) => block_http_get::<EmptyType, EmptyType, DoubanCollectionRes>(
+      client,
+      &collection_url(name),
+      headers,
+      None,
+      None,
+    )
+    .map(|r| r.into()),
+  }
+}
+
+/// Rexxar api rejects requests without a douban referer.
+fn with_referer(headers: Option<HeaderMap>) -> HeaderMap {
+  let mut headers = headers.unwrap_or_default();
+  headers
+    .entry(REFERER)
+    .or_insert(HeaderValue::from_static("https://m.douban.com/"));
+  headers
+}
+
+fn collection_url(name: &str) -> String {
+  format!("{}/{}/items?start=0&count=50", COLLECTION_ENDPOINT, name)
+}
+
+enum DoubanBoard {
+  Group,
+  Collection(&'static str),
+}
+
+impl TryFrom<&TrendingReq> for DoubanBoard {
+  type Error = TrendingError;
+
+  fn try_from(value: &TrendingReq) -> Result<Self> {
+    match value.board.as_deref().unwrap_or(BOARDS[0]) {
+      "group" => Ok(Self::Group),
+      "movie" => Ok(Self::Collection("movie_weekly_best")),
+      "book" => Ok(Self::Collection("book_fiction_hot_weekly")),
+      other => UnsupportedBoardSnafu {
+        platform: PlatformType::Douban.to_str(),
+        board: other,
+      }
+      .fail(),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanGroupRes {
+  #[serde(rename = "topics", skip_serializing_if = "Vec::is_empty", default)]
+  topics: Vec<DoubanTopic>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanTopic {
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "comments_count", skip_serializing_if = "Option::is_none")]
+  comments_count: Option<u64>,
+
+  #[serde(rename = "cover_url", skip_serializing_if = "Option::is_none")]
+  cover_url: Option<String>,
+
+  #[serde(rename = "group", skip_serializing_if = "Option::is_none")]
+  group: Option<DoubanGroup>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanGroup {
+  #[serde(rename = "name")]
+  name: String,
+}
+
+impl From<DoubanTopic> for TrendingRes {
+  fn from(value: DoubanTopic) -> Self {
+    Self {
+      title: value.title,
+      url: value.url,
+      trend: value.comments_count.map(|c| c.to_string()),
+      medias: not_empty_str(value.cover_url).map(|c| vec![MediaData::new_image(c)]),
+      desc: value.group.map(|g| g.name),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<DoubanGroupRes> for TrendingsRes {
+  fn from(value: DoubanGroupRes) -> Self {
+    Self {
+      platform: PlatformType::Douban,
+      result: value.topics.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanCollectionRes {
+  #[serde(
+    rename = "subject_collection_items",
+    skip_serializing_if = "Vec::is_empty",
+    default
+  )]
+  items: Vec<DoubanSubject>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanSubject {
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "rating", skip_serializing_if = "Option::is_none")]
+  rating: Option<DoubanRating>,
+
+  #[serde(rename = "cover", skip_serializing_if = "Option::is_none")]
+  cover: Option<DoubanCover>,
+
+  #[serde(rename = "card_subtitle", skip_serializing_if = "Option::is_none")]
+  card_subtitle: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanRating {
+  #[serde(rename = "value")]
+  value: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DoubanCover {
+  #[serde(rename = "url")]
+  url: String,
+}
+
+impl From<DoubanSubject> for TrendingRes {
+  fn from(value: DoubanSubject) -> Self {
+    Self {
+      title: value.title,
+      url: value.url,
+      trend: value.rating.map(|r| format!("{:.1}", r.value)),
+      medias: value.cover.map(|c| vec![MediaData::new_image(c.url)]),
+      desc: not_empty_str(value.card_subtitle),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<DoubanCollectionRes> for TrendingsRes {
+  fn from(value: DoubanCollectionRes) -> Self {
+    Self {
+      platform: PlatformType::Douban,
+      result: value.items.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, http_get},
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://www.douyin.com/aweme/v1/web/hot/search/list/?device_platform=webapp&aid=6383&channel=channel_pc_web&detail_list=1";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, DouyinRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, DouyinRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DouyinRes {
+  #[serde(rename = "data")]
+  data: DouyinData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DouyinData {
+  #[serde(rename = "word_list", skip_serializing_if = "Vec::is_empty", default)]
+  word_list: Vec<DouyinWord>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DouyinWord {
+  #[serde(rename = "word")]
+  word: String,
+
+  #[serde(rename = "sentence_id")]
+  sentence_id: String,
+
+  #[serde(rename = "hot_value", skip_serializing_if = "Option::is_none")]
+  hot_value: Option<u64>,
+
+  #[serde(rename = "label", skip_serializing_if = "Option::is_none")]
+  label: Option<u32>,
+
+  #[serde(rename = "word_cover", skip_serializing_if = "Option::is_none")]
+  word_cover: Option<DouyinCover>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DouyinCover {
+  #[serde(rename = "url_list", skip_serializing_if = "Vec::is_empty", default)]
+  url_list: Vec<String>,
+}
+
+/// Maps douyin's numeric label to its badge, unknown labels are dropped.
+fn label_name(label: u32) -> Option<String> {
+  match label {
+    1 => Some("new".to_string()),
+    3 => Some("hot".to_string()),
+    4 => Some("boiling".to_string()),
+    _ => None,
+  }
+}
+
+impl From<DouyinWord> for TrendingRes {
+  fn from(value: DouyinWord) -> Self {
+    Self {
+      url: format!("https://www.douyin.com/hot/{}", value.sentence_id),
+      title: value.word,
+      trend: value.hot_value.map(|v| v.to_string()),
+      medias: value
+        .word_cover
+        .and_then(|c| c.url_list.into_iter().next())
+        .map(|u| vec![MediaData::new_image(u)]),
+      label: value.label.and_then(label_name),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<DouyinRes> for TrendingsRes {
+  fn from(value: DouyinRes) -> Self {
+    Self {
+      platform: PlatformType::Douyin,
+      result: value.data.word_list.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
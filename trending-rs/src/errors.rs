@@ -1,4 +1,4 @@
-use std::backtrace::Backtrace;
+use std::{backtrace::Backtrace, time::Duration};
 
 use snafu::{Location, Snafu};
 
@@ -27,6 +27,25 @@ pub enum TrendingError {
     backtrace: Backtrace,
   },
 
+  /// Error status of a response, `retry_after` is taken from its `Retry-After` header.
+  #[snafu(display("Failed to process http request"))]
+  HttpStatus {
+    #[snafu(source)]
+    source: reqwest::Error,
+    retry_after: Option<Duration>,
+    #[snafu(implicit)]
+    location: Location,
+  },
+
+  /// Request that outlived the client timeout on wasm32, where the timeout is
+  /// enforced by the client rather than by reqwest.
+  #[snafu(display("Request timed out after {:?}", timeout))]
+  RequestTimeout {
+    timeout: Duration,
+    #[snafu(implicit)]
+    location: Location,
+  },
+
   #[snafu(display("Invalid http header name: {}", name))]
   ReqwestHeaderName {
     name: String,
@@ -61,6 +80,30 @@ pub enum TrendingError {
     location: Location,
   },
 
+  #[snafu(display("Unsupported platform: {}", platform))]
+  UnsupportedPlatform {
+    platform: String,
+    #[snafu(implicit)]
+    location: Location,
+  },
+
+  #[snafu(display("Unsupported board of {}: {}", platform, board))]
+  UnsupportedBoard {
+    platform: String,
+    board: String,
+    #[snafu(implicit)]
+    location: Location,
+  },
+
+  #[snafu(display("Invalid param of {}: {}={}", platform, param, value))]
+  InvalidParam {
+    platform: String,
+    param: String,
+    value: String,
+    #[snafu(implicit)]
+    location: Location,
+  },
+
   #[snafu(display("{}", message))]
   PlainMessage {
     message: String,
@@ -73,3 +116,79 @@ pub enum TrendingError {
   // location: Location,
   // },
 }
+
+impl TrendingError {
+  pub fn detailed_message(&self) -> String {
+    let mut message = self.to_string();
+    let mut source = std::error::Error::source(self);
+    while let Some(err) = source {
+      message.push_str(": ");
+      message.push_str(&err.to_string());
+      source = err.source();
+    }
+    message
+  }
+
+  pub fn is_timeout(&self) -> bool {
+    match self {
+      TrendingError::ReqwestClient { source, .. } => source.is_timeout(),
+      TrendingError::RequestTimeout { .. } => true,
+      _ => false,
+    }
+  }
+
+  pub fn is_decode(&self) -> bool {
+    match self {
+      TrendingError::ReqwestClient { source, .. } => source.is_decode(),
+      TrendingError::DeserializeJson { .. } => true,
+      _ => false,
+    }
+  }
+
+  pub fn status(&self) -> Option<u16> {
+    match self {
+      TrendingError::ReqwestClient { source, .. } | TrendingError::HttpStatus { source, .. } => {
+        source.status().map(|s| s.as_u16())
+      }
+      _ => None,
+    }
+  }
+
+  pub fn endpoint(&self) -> Option<&str> {
+    match self {
+      TrendingError::ReqwestClient { source, .. } | TrendingError::HttpStatus { source, .. } => {
+        source.url().map(|u| u.as_str())
+      }
+      _ => None,
+    }
+  }
+
+  pub(crate) fn is_retryable(&self) -> bool {
+    match self {
+      TrendingError::ReqwestClient { source, .. } => {
+        #[cfg(not(target_arch = "wasm32"))]
+        let connect = source.is_connect();
+        #[cfg(target_arch = "wasm32")]
+        let connect = false;
+        source.is_timeout()
+          || connect
+          || source
+            .status()
+            .is_some_and(|s| s.is_server_error() || s.as_u16() == 429)
+      }
+      TrendingError::HttpStatus { source, .. } => source
+        .status()
+        .is_some_and(|s| s.is_server_error() || s.as_u16() == 429),
+      TrendingError::RequestTimeout { .. } => true,
+      _ => false,
+    }
+  }
+
+  /// How long the platform asked to wait before the next request.
+  pub fn retry_after(&self) -> Option<Duration> {
+    match self {
+      TrendingError::HttpStatus { retry_after, .. } => *retry_after,
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,134 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, Url, header::HeaderMap};
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get_text;
+use crate::{
+  common::{PlatformType, TrendingReq, TrendingRes, TrendingsRes, http_get_text},
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://github.com/trending";
+
+/// Repositories of a language (`language`) and a timeframe (`since`, one of
+/// `daily`, `weekly` and `monthly`), both taken from the request params.
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  http_get_text::<GithubTrendingReq>(client, &trending_url(req), headers, Some(&req.into()))
+    .await
+    .map(|html| parse_trendings(&html))
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  block_http_get_text::<GithubTrendingReq>(client, &trending_url(req), headers, Some(&req.into()))
+    .map(|html| parse_trendings(&html))
+}
+
+/// The language is a path segment, escaped so that e.g. `c#` and `c++` stay in the path.
+fn trending_url(req: &TrendingReq) -> String {
+  let mut url = Url::parse(TRENDING_ENDPOINT).unwrap();
+  if let (Some(language), Ok(mut segments)) = (req.param("language"), url.path_segments_mut()) {
+    segments.push(language);
+  }
+  url.to_string()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct GithubTrendingReq {
+  #[serde(rename = "since", skip_serializing_if = "Option::is_none")]
+  since: Option<String>,
+}
+
+impl From<&TrendingReq> for GithubTrendingReq {
+  fn from(value: &TrendingReq) -> Self {
+    Self {
+      since: value.param("since").map(str::to_string),
+    }
+  }
+}
+
+fn parse_trendings(html: &str) -> TrendingsRes {
+  let document = Html::parse_document(html);
+  let row = Selector::parse("article.Box-row").unwrap();
+  let result = document.select(&row).filter_map(parse_repository).collect();
+  TrendingsRes {
+    platform: PlatformType::Github,
+    result,
+  }
+}
+
+fn parse_repository(row: ElementRef) -> Option<TrendingRes> {
+  let link = Selector::parse("h2 a").unwrap();
+  let desc = Selector::parse("p").unwrap();
+  let language = Selector::parse(r#"[itemprop="programmingLanguage"]"#).unwrap();
+  let stars = Selector::parse("span.float-sm-right").unwrap();
+  let text = |element: ElementRef| {
+    let text = element.text().collect::<Vec<_>>().join(" ");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+  };
+
+  let link = row.select(&link).next()?;
+  let path = link.value().attr("href")?;
+  Some(TrendingRes {
+    // "owner / repo" with newlines in between
+    title: text(link)?.replace(" / ", "/"),
+    url: format!("https://github.com{}", path),
+    // e.g. "1,234 stars today"
+    trend: row.select(&stars).next().and_then(text),
+    language: row.select(&language).next().and_then(text),
+    desc: row.select(&desc).next().and_then(text),
+    author: path.split('/').nth(1).map(str::to_string),
+    ..Default::default()
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn trending_url_escapes_language() {
+    let req = TrendingReq::new().with_param("language", "c#");
+    assert_eq!(trending_url(&req), "https://github.com/trending/c%23");
+    let req = TrendingReq::new().with_param("language", "c++");
+    assert_eq!(trending_url(&req), "https://github.com/trending/c++");
+    assert_eq!(trending_url(&TrendingReq::new()), TRENDING_ENDPOINT);
+  }
+
+  #[test]
+  fn parse_trendings_of_page() {
+    let html = include_str!("../tests/fixtures/github_trending.html");
+    let res = parse_trendings(html);
+    assert_eq!(res.result.len(), 2);
+
+    let first = &res.result[0];
+    assert_eq!(first.title, "rust-lang/rust");
+    assert_eq!(first.url, "https://github.com/rust-lang/rust");
+    assert_eq!(first.author.as_deref(), Some("rust-lang"));
+    assert_eq!(first.language.as_deref(), Some("Rust"));
+    assert_eq!(first.label, None);
+    assert_eq!(first.trend.as_deref(), Some("1,234 stars today"));
+    assert_eq!(
+      first.desc.as_deref(),
+      Some("Empowering everyone to build reliable and efficient software.")
+    );
+
+    // repositories without language and description
+    let second = &res.result[1];
+    assert_eq!(second.title, "octocat/hello-world");
+    assert_eq!(second.label, None);
+    assert_eq!(second.desc, None);
+  }
+}
@@ -0,0 +1,151 @@
+use futures_util::future::join_all;
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get},
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://hacker-news.firebaseio.com/v0/topstories.json";
+pub const ITEM_ENDPOINT: &str = "https://hacker-news.firebaseio.com/v0/item";
+
+/// Stories of the front page, each one costs a request.
+const FRONT_PAGE_SIZE: usize = 30;
+
+/// Threads fetching stories of the blocking client, each one takes a slice of
+/// the front page.
+#[cfg(feature = "blocking")]
+const BLOCKING_WORKERS: usize = 6;
+
+/// Stories are fetched concurrently, the ones failing to load are left out.
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  let ids = http_get::<EmptyType, EmptyType, Vec<u64>>(
+    client,
+    TRENDING_ENDPOINT,
+    headers.clone(),
+    None,
+    None,
+  )
+  .await?;
+  let items = ids.into_iter().take(FRONT_PAGE_SIZE).map(|id| {
+    let headers = headers.clone();
+    async move {
+      http_get::<EmptyType, EmptyType, Option<HackerNewsItem>>(
+        client,
+        &item_url(id),
+        headers,
+        None,
+        None,
+      )
+      .await
+    }
+  });
+  into_trendings(join_all(items).await)
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  let ids = block_http_get::<EmptyType, EmptyType, Vec<u64>>(
+    client,
+    TRENDING_ENDPOINT,
+    headers.clone(),
+    None,
+    None,
+  )?;
+  let ids = &ids[..ids.len().min(FRONT_PAGE_SIZE)];
+  let chunk_size = ids.len().div_ceil(BLOCKING_WORKERS).max(1);
+  let items = std::thread::scope(|scope| {
+    let handles: Vec<_> = ids
+      .chunks(chunk_size)
+      .map(|ids| {
+        let headers = &headers;
+        scope.spawn(move || {
+          ids
+            .iter()
+            .map(|id| {
+              block_http_get::<EmptyType, EmptyType, Option<HackerNewsItem>>(
+                client,
+                &item_url(*id),
+                headers.clone(),
+                None,
+                None,
+              )
+            })
+            .collect::<Vec<_>>()
+        })
+      })
+      .collect();
+    handles
+      .into_iter()
+      .flat_map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+      .collect::<Vec<_>>()
+  });
+  into_trendings(items)
+}
+
+fn item_url(id: u64) -> String {
+  format!("{}/{}.json", ITEM_ENDPOINT, id)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HackerNewsItem {
+  #[serde(rename = "id")]
+  id: u64,
+
+  #[serde(rename = "title", default)]
+  title: String,
+
+  #[serde(rename = "url", skip_serializing_if = "Option::is_none")]
+  url: Option<String>,
+
+  #[serde(rename = "score", skip_serializing_if = "Option::is_none")]
+  score: Option<u64>,
+
+  #[serde(rename = "by", skip_serializing_if = "Option::is_none")]
+  by: Option<String>,
+
+  #[serde(rename = "time", skip_serializing_if = "Option::is_none")]
+  time: Option<u64>,
+}
+
+impl From<HackerNewsItem> for TrendingRes {
+  fn from(value: HackerNewsItem) -> Self {
+    Self {
+      title: value.title,
+      // ask and show stories have no external url
+      url: value
+        .url
+        .unwrap_or_else(|| format!("https://news.ycombinator.com/item?id={}", value.id)),
+      trend: value.score.map(|s| s.to_string()),
+      author: value.by,
+      time: value.time,
+      ..Default::default()
+    }
+  }
+}
+
+/// Skips the stories that failed to load, unless all of them failed.
+fn into_trendings(items: Vec<Result<Option<HackerNewsItem>>>) -> Result<TrendingsRes> {
+  let mut result = Vec::with_capacity(items.len());
+  let mut first_err = None;
+  for item in items {
+    match item {
+      Ok(item) => result.extend(item.map(TrendingRes::from)),
+      Err(err) => {
+        first_err.get_or_insert(err);
+      }
+    }
+  }
+  match first_err {
+    Some(err) if result.is_empty() => Err(err),
+    _ => Ok(TrendingsRes {
+      platform: PlatformType::HackerNews,
+      result,
+    }),
+  }
+}
@@ -1,28 +1,127 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "blocking")]
 use crate::common::block_http_get;
 use crate::{
-  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get},
-  errors::Result,
+  common::{
+    EmptyType, PlatformType, SearchFilter, SearchReq, SearchRes, SearchesRes, TrendingReq,
+    TrendingRes, TrendingsRes, de_count, http_get, not_empty_str, strip_html,
+  },
+  errors::{Result, TrendingError, UnsupportedBoardSnafu},
 };
 
-pub const TRENDING_ENDPOINT: &'static str =
-  "https://m.hupu.com/api/v2/bbs/topicThreads?topicId=1&page=1";
+pub const TRENDING_ENDPOINT: &str = "https://m.hupu.com/api/v2/bbs/topicThreads";
+pub const SEARCH_ENDPOINT: &str = "https://m.hupu.com/api/v2/search2?type=posts&topicId=0";
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, HupuRes>(client, TRENDING_ENDPOINT, None, None, None)
-    .await
-    .map(|r| r.into())
+/// Filters of [`SearchReq`] honored by the search.
+pub const SEARCH_FILTERS: &[SearchFilter] = &[];
+
+/// Results per search page, fixed by hupu so the size of the request is
+/// reported as ignored.
+const SEARCH_PAGE_SIZE: u32 = 20;
+
+/// Boards of hupu, the first one is used when the request has no board. The
+/// chinese names such as `步行街` and plain topic ids are accepted as well.
+pub const BOARDS: [&str; 3] = ["bxj", "nba", "soccer"];
+
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  http_get::<HupuTrendingReq, EmptyType, HupuRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .map(|r| r.into())
+}
+
+pub async fn search(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  http_get::<HupuSearchReq, EmptyType, HupuSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .and_then(|r| SearchesRes::from(r).with_next_page(req, SEARCH_PAGE_SIZE))
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  block_http_get::<HupuTrendingReq, EmptyType, HupuRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .map(|r| r.into())
 }
 
 #[cfg(feature = "blocking")]
-pub fn blocking_trending(client: &BlockClient) -> Result<TrendingsRes> {
-  block_http_get::<EmptyType, EmptyType, HupuRes>(client, TRENDING_ENDPOINT, None, None, None)
-    .map(|r| r.into())
+pub fn blocking_search(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  block_http_get::<HupuSearchReq, EmptyType, HupuSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .and_then(|r| SearchesRes::from(r).with_next_page(req, SEARCH_PAGE_SIZE))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HupuTrendingReq {
+  #[serde(rename = "topicId")]
+  topic_id: String,
+
+  #[serde(rename = "page")]
+  page: u32,
+}
+
+impl TryFrom<&TrendingReq> for HupuTrendingReq {
+  type Error = TrendingError;
+
+  fn try_from(value: &TrendingReq) -> Result<Self> {
+    let topic_id = match value.board.as_deref().unwrap_or(BOARDS[0]) {
+      "bxj" | "步行街" => "1",
+      "nba" | "NBA" => "2",
+      "soccer" | "足球" => "3",
+      id if id.parse::<u32>().is_ok() => id,
+      other => {
+        return UnsupportedBoardSnafu {
+          platform: PlatformType::Hupu.to_str(),
+          board: other,
+        }
+        .fail();
+      }
+    };
+    Ok(Self {
+      topic_id: topic_id.to_string(),
+      page: value.param_u32(PlatformType::Hupu, "page")?.unwrap_or(1),
+    })
+  }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -48,6 +147,13 @@ struct HupuThread {
 
   #[serde(rename = "url")]
   url: String,
+
+  #[serde(rename = "replies", deserialize_with = "de_count", default)]
+  replies: Option<u64>,
+
+  /// Lights are the upvotes of hupu.
+  #[serde(rename = "lights", deserialize_with = "de_count", default)]
+  lights: Option<u64>,
 }
 
 impl From<HupuThread> for TrendingRes {
@@ -55,7 +161,9 @@ impl From<HupuThread> for TrendingRes {
     Self {
       title: value.title,
       url: value.url,
-      trend: None,
+      trend: value.lights.map(|l| l.to_string()),
+      comments: value.replies,
+      ..Default::default()
     }
   }
 }
@@ -68,3 +176,105 @@ impl From<HupuRes> for TrendingsRes {
     }
   }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HupuSearchReq<'a> {
+  #[serde(rename = "keyword")]
+  keyword: &'a str,
+
+  #[serde(rename = "page")]
+  page: u32,
+}
+
+impl<'a> TryFrom<&'a SearchReq> for HupuSearchReq<'a> {
+  type Error = TrendingError;
+
+  fn try_from(value: &'a SearchReq) -> Result<Self> {
+    Ok(Self {
+      keyword: &value.keyword,
+      // hupu counts pages from 1
+      page: value
+        .page
+        .as_ref()
+        .map_or(Ok(0), |p| p.number(PlatformType::Hupu, SEARCH_PAGE_SIZE))?
+        .saturating_add(1),
+    })
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HupuSearchRes {
+  #[serde(rename = "data")]
+  data: HupuSearchData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HupuSearchData {
+  #[serde(rename = "searchRes")]
+  search_res: HupuSearchList,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HupuSearchList {
+  #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
+  data: Vec<HupuSearchThread>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HupuSearchThread {
+  #[serde(rename = "tid", deserialize_with = "de_count", default)]
+  tid: Option<u64>,
+
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
+  username: Option<String>,
+
+  #[serde(rename = "addtime", deserialize_with = "de_count", default)]
+  add_time: Option<u64>,
+
+  #[serde(rename = "replies", deserialize_with = "de_count", default)]
+  replies: Option<u64>,
+
+  #[serde(rename = "lights", deserialize_with = "de_count", default)]
+  lights: Option<u64>,
+}
+
+impl From<HupuSearchThread> for SearchRes {
+  fn from(value: HupuSearchThread) -> Self {
+    Self {
+      title: strip_html(&value.title),
+      url: format!(
+        "https://bbs.hupu.com/{}.html",
+        value.tid.unwrap_or_default()
+      ),
+      time: value.add_time,
+      medias: None,
+      desc: None,
+      author: not_empty_str(value.username),
+      likes: value.lights,
+      comments: value.replies,
+      kind: None,
+      category: None,
+    }
+  }
+}
+
+impl From<HupuSearchRes> for SearchesRes {
+  fn from(value: HupuSearchRes) -> Self {
+    Self {
+      platform: PlatformType::Hupu,
+      ignored: Vec::new(),
+      next: None,
+      result: value
+        .data
+        .search_res
+        .data
+        .into_iter()
+        .filter(|r| r.tid.is_some())
+        .map(|r| r.into())
+        .collect(),
+    }
+  }
+}
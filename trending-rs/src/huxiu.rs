@@ -0,0 +1,117 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_post;
+use crate::{
+  common::{
+    EmptyType, HttpBody, MediaData, PlatformType, TrendingRes, TrendingsRes, http_post,
+    not_empty_str,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://api-article.huxiu.com/web/article/articleList";
+
+/// Latest articles of huxiu, which has no public hot list.
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_post::<EmptyType, HuxiuTrendingReq, HuxiuRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    Some(HttpBody::form(&HuxiuTrendingReq::default())),
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_post::<EmptyType, HuxiuTrendingReq, HuxiuRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    Some(HttpBody::form(&HuxiuTrendingReq::default())),
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HuxiuTrendingReq {
+  #[serde(rename = "platform")]
+  platform: &'static str,
+
+  #[serde(rename = "pagesize")]
+  page_size: u32,
+}
+
+impl Default for HuxiuTrendingReq {
+  fn default() -> Self {
+    Self {
+      platform: "www",
+      page_size: 30,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HuxiuRes {
+  #[serde(rename = "data")]
+  data: HuxiuData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HuxiuData {
+  #[serde(rename = "dataList", skip_serializing_if = "Vec::is_empty", default)]
+  data_list: Vec<HuxiuArticle>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HuxiuArticle {
+  #[serde(rename = "aid")]
+  aid: String,
+
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "pic_path", skip_serializing_if = "Option::is_none")]
+  pic_path: Option<String>,
+
+  #[serde(rename = "dateline", skip_serializing_if = "Option::is_none")]
+  dateline: Option<String>,
+
+  #[serde(rename = "user_info", skip_serializing_if = "Option::is_none")]
+  user_info: Option<HuxiuUser>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HuxiuUser {
+  #[serde(rename = "username")]
+  username: String,
+}
+
+impl From<HuxiuArticle> for TrendingRes {
+  fn from(value: HuxiuArticle) -> Self {
+    Self {
+      title: value.title,
+      url: format!("https://www.huxiu.com/article/{}.html", value.aid),
+      medias: not_empty_str(value.pic_path).map(|p| vec![MediaData::new_image(p)]),
+      author: value.user_info.map(|u| u.username),
+      time: value.dateline.and_then(|d| d.parse().ok()),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<HuxiuRes> for TrendingsRes {
+  fn from(value: HuxiuRes) -> Self {
+    Self {
+      platform: PlatformType::Huxiu,
+      result: value.data.data_list.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
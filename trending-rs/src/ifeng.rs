@@ -0,0 +1,84 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str,
+    parse_datetime,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://shankapi.ifeng.com/autumn/index/getHotNews/hotNews";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, IfengRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, IfengRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IfengRes {
+  #[serde(rename = "data")]
+  data: IfengData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IfengData {
+  #[serde(rename = "hotNews", skip_serializing_if = "Vec::is_empty", default)]
+  hot_news: Vec<IfengNews>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IfengNews {
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "summary", skip_serializing_if = "Option::is_none")]
+  summary: Option<String>,
+
+  #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
+  thumbnail: Option<String>,
+
+  #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
+  source: Option<String>,
+
+  #[serde(rename = "newsTime", skip_serializing_if = "Option::is_none")]
+  news_time: Option<String>,
+}
+
+impl From<IfengNews> for TrendingRes {
+  fn from(value: IfengNews) -> Self {
+    Self {
+      title: value.title,
+      url: value.url,
+      medias: not_empty_str(value.thumbnail).map(|t| vec![MediaData::new_image(t)]),
+      desc: not_empty_str(value.summary),
+      author: not_empty_str(value.source),
+      time: value.news_time.as_deref().and_then(parse_datetime),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<IfengRes> for TrendingsRes {
+  fn from(value: IfengRes) -> Self {
+    Self {
+      platform: PlatformType::Ifeng,
+      result: value.data.hot_news.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
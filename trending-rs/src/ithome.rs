@@ -0,0 +1,78 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, de_count, http_get,
+    not_empty_str, parse_datetime,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://api.ithome.com/json/newslist/rank";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, IthomeRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, IthomeRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IthomeRes {
+  #[serde(rename = "daytop", skip_serializing_if = "Vec::is_empty", default)]
+  day_top: Vec<IthomeNews>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct IthomeNews {
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "image", skip_serializing_if = "Option::is_none")]
+  image: Option<String>,
+
+  #[serde(rename = "newsauthor", skip_serializing_if = "Option::is_none")]
+  author: Option<String>,
+
+  #[serde(rename = "postdate", skip_serializing_if = "Option::is_none")]
+  post_date: Option<String>,
+
+  #[serde(rename = "hitcount", deserialize_with = "de_count", default)]
+  hit_count: Option<u64>,
+}
+
+impl From<IthomeNews> for TrendingRes {
+  fn from(value: IthomeNews) -> Self {
+    Self {
+      title: value.title,
+      url: value.url,
+      trend: value.hit_count.map(|h| h.to_string()),
+      medias: not_empty_str(value.image).map(|i| vec![MediaData::new_image(i)]),
+      author: not_empty_str(value.author),
+      time: value.post_date.as_deref().and_then(parse_datetime),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<IthomeRes> for TrendingsRes {
+  fn from(value: IthomeRes) -> Self {
+    Self {
+      platform: PlatformType::Ithome,
+      result: value.day_top.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, de_count, http_get, not_empty_str},
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str =
+  "https://api.juejin.cn/content_api/v1/content/article_rank?category_id=1&type=hot";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, JuejinRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, JuejinRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JuejinRes {
+  #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
+  data: Vec<JuejinArticle>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JuejinArticle {
+  #[serde(rename = "content")]
+  content: JuejinContent,
+
+  #[serde(rename = "author", skip_serializing_if = "Option::is_none")]
+  author: Option<JuejinAuthor>,
+
+  #[serde(rename = "content_counter", skip_serializing_if = "Option::is_none")]
+  counter: Option<JuejinCounter>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JuejinContent {
+  #[serde(rename = "content_id")]
+  content_id: String,
+
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "brief", skip_serializing_if = "Option::is_none")]
+  brief: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JuejinAuthor {
+  #[serde(rename = "name")]
+  name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JuejinCounter {
+  #[serde(rename = "like", deserialize_with = "de_count", default)]
+  like: Option<u64>,
+
+  #[serde(rename = "view", deserialize_with = "de_count", default)]
+  view: Option<u64>,
+
+  #[serde(rename = "comment_count", deserialize_with = "de_count", default)]
+  comment_count: Option<u64>,
+}
+
+impl From<JuejinArticle> for TrendingRes {
+  fn from(value: JuejinArticle) -> Self {
+    let counter = value.counter.as_ref();
+    Self {
+      url: format!("https://juejin.cn/post/{}", value.content.content_id),
+      title: value.content.title,
+      // the like count is the heat, `hot_rank` is an opaque score
+      trend: counter.and_then(|c| c.like).map(|l| l.to_string()),
+      desc: not_empty_str(value.content.brief),
+      author: value.author.map(|a| a.name),
+      reads: counter.and_then(|c| c.view),
+      comments: counter.and_then(|c| c.comment_count),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<JuejinRes> for TrendingsRes {
+  fn from(value: JuejinRes) -> Self {
+    Self {
+      platform: PlatformType::Juejin,
+      result: value.data.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
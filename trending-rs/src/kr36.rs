@@ -0,0 +1,140 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_post;
+use crate::{
+  common::{
+    EmptyType, HttpBody, MediaData, PlatformType, TrendingRes, TrendingsRes, http_post,
+    not_empty_str,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://gateway.36kr.com/api/mis/nav/home/nav/rank/hot";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_post::<EmptyType, Kr36TrendingReq, Kr36Res>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    Some(HttpBody::json(&Kr36TrendingReq::default())),
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_post::<EmptyType, Kr36TrendingReq, Kr36Res>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    Some(HttpBody::json(&Kr36TrendingReq::default())),
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Kr36TrendingReq {
+  #[serde(rename = "partner_id")]
+  partner_id: &'static str,
+
+  #[serde(rename = "param")]
+  param: Kr36Param,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Kr36Param {
+  #[serde(rename = "siteId")]
+  site_id: u32,
+
+  #[serde(rename = "platformId")]
+  platform_id: u32,
+}
+
+impl Default for Kr36TrendingReq {
+  fn default() -> Self {
+    Self {
+      partner_id: "wap",
+      param: Kr36Param {
+        site_id: 1,
+        platform_id: 2,
+      },
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Kr36Res {
+  #[serde(rename = "data")]
+  data: Kr36Data,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Kr36Data {
+  #[serde(rename = "hotRankList", skip_serializing_if = "Vec::is_empty", default)]
+  hot_rank_list: Vec<Kr36Item>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Kr36Item {
+  #[serde(rename = "itemId")]
+  item_id: u64,
+
+  #[serde(rename = "templateMaterial")]
+  material: Kr36Material,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Kr36Material {
+  #[serde(rename = "widgetTitle")]
+  title: String,
+
+  #[serde(rename = "widgetImage", skip_serializing_if = "Option::is_none")]
+  image: Option<String>,
+
+  #[serde(rename = "authorName", skip_serializing_if = "Option::is_none")]
+  author_name: Option<String>,
+
+  #[serde(rename = "publishTime", skip_serializing_if = "Option::is_none")]
+  publish_time: Option<u64>,
+
+  #[serde(rename = "statRead", skip_serializing_if = "Option::is_none")]
+  stat_read: Option<u64>,
+}
+
+impl From<Kr36Item> for TrendingRes {
+  fn from(value: Kr36Item) -> Self {
+    let material = value.material;
+    Self {
+      title: material.title,
+      url: format!("https://www.36kr.com/p/{}", value.item_id),
+      trend: material.stat_read.map(|r| r.to_string()),
+      medias: not_empty_str(material.image).map(|i| vec![MediaData::new_image(i)]),
+      author: not_empty_str(material.author_name),
+      // milliseconds
+      time: material.publish_time.map(|t| t / 1000),
+      reads: material.stat_read,
+      ..Default::default()
+    }
+  }
+}
+
+impl From<Kr36Res> for TrendingsRes {
+  fn from(value: Kr36Res) -> Self {
+    Self {
+      platform: PlatformType::Kr36,
+      result: value
+        .data
+        .hot_rank_list
+        .into_iter()
+        .map(|r| r.into())
+        .collect(),
+    }
+  }
+}
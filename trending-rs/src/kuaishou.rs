@@ -0,0 +1,135 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, Url, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_post;
+use crate::{
+  common::{
+    EmptyType, HttpBody, MediaData, PlatformType, TrendingRes, TrendingsRes, http_post,
+    not_empty_str,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://www.kuaishou.com/graphql";
+
+const HOT_RANK_QUERY: &str = "query visionHotRank($page: String) { visionHotRank(page: $page) \
+  { result pcursor items { id hotValue name rank poster photoIds } } }";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_post::<EmptyType, KuaishouTrendingReq, KuaishouRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    Some(HttpBody::json(&KuaishouTrendingReq::default())),
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_post::<EmptyType, KuaishouTrendingReq, KuaishouRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    Some(HttpBody::json(&KuaishouTrendingReq::default())),
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct KuaishouTrendingReq {
+  #[serde(rename = "operationName")]
+  operation_name: &'static str,
+
+  #[serde(rename = "variables")]
+  variables: KuaishouVariables,
+
+  #[serde(rename = "query")]
+  query: &'static str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct KuaishouVariables {
+  #[serde(rename = "page")]
+  page: &'static str,
+}
+
+impl Default for KuaishouTrendingReq {
+  fn default() -> Self {
+    Self {
+      operation_name: "visionHotRank",
+      variables: KuaishouVariables { page: "home" },
+      query: HOT_RANK_QUERY,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct KuaishouRes {
+  #[serde(rename = "data")]
+  data: KuaishouData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct KuaishouData {
+  #[serde(rename = "visionHotRank")]
+  hot_rank: KuaishouHotRank,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct KuaishouHotRank {
+  #[serde(rename = "items", skip_serializing_if = "Vec::is_empty", default)]
+  items: Vec<KuaishouItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct KuaishouItem {
+  #[serde(rename = "name")]
+  name: String,
+
+  #[serde(rename = "rank", skip_serializing_if = "Option::is_none")]
+  rank: Option<u32>,
+
+  #[serde(rename = "hotValue", skip_serializing_if = "Option::is_none")]
+  hot_value: Option<String>,
+
+  #[serde(rename = "poster", skip_serializing_if = "Option::is_none")]
+  poster: Option<String>,
+}
+
+impl From<KuaishouItem> for TrendingRes {
+  fn from(value: KuaishouItem) -> Self {
+    let url = Url::parse_with_params(
+      "https://www.kuaishou.com/search/video",
+      &[("searchKey", &value.name)],
+    )
+    .map(|u| u.to_string())
+    .unwrap_or_default();
+    Self {
+      title: value.name,
+      url,
+      trend: not_empty_str(value.hot_value),
+      medias: not_empty_str(value.poster).map(|p| vec![MediaData::new_image(p)]),
+      rank: value.rank,
+      ..Default::default()
+    }
+  }
+}
+
+impl From<KuaishouRes> for TrendingsRes {
+  fn from(value: KuaishouRes) -> Self {
+    let mut items = value.data.hot_rank.items;
+    // pinned items come first in the response, order by rank instead
+    items.sort_by_key(|i| i.rank.unwrap_or(u32::MAX));
+    Self {
+      platform: PlatformType::Kuaishou,
+      result: items.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
@@ -2,68 +2,114 @@
 //!
 //! Trending is a library for retrieving trending information from media platforms. It currently supports the following platforms:
 //!
-//! | platform      | trending | search | site                        |
-//! | :-----------: | :------: | :----: | :-------------------------- |
-//! | hupu          | ✓        | -      | <https://m.hupu.com>        |
-//! | tencent       | ✓        | ✓      | <https://news.qq.com>       |
-//! | netease       | ✓        | ✓      | <https://m.163.com>         |
-//! | tieba         | ✓        | -      | <https://www.tieba.com>     |
-//! | toutiao       | ✓        | -      | <https://www.toutiao.com>   |
-//! | weibo         | ✓        | -      | <https://weibo.com>         |
-//! | zhihu         | ✓        | -      | <https://www.zhihu.com>     |
+//! | platform      | trending | search | site                            |
+//! | :-----------: | :------: | :----: | :------------------------------ |
+//! | 36kr          | ✓        | -      | <https://www.36kr.com>          |
+//! | baidu         | ✓        | -      | <https://top.baidu.com>         |
+//! | bilibili      | ✓        | -      | <https://www.bilibili.com>      |
+//! | csdn          | ✓        | -      | <https://blog.csdn.net>         |
+//! | douban        | ✓        | -      | <https://m.douban.com>          |
+//! | douyin        | ✓        | -      | <https://www.douyin.com>        |
+//! | github        | ✓        | -      | <https://github.com/trending>   |
+//! | hackernews    | ✓        | -      | <https://news.ycombinator.com>  |
+//! | hupu          | ✓        | ✓      | <https://m.hupu.com>            |
+//! | huxiu         | ✓        | -      | <https://www.huxiu.com/article> |
+//! | ifeng         | ✓        | -      | <https://www.ifeng.com>         |
+//! | ithome        | ✓        | -      | <https://www.ithome.com>        |
+//! | juejin        | ✓        | -      | <https://juejin.cn>             |
+//! | kuaishou      | ✓        | -      | <https://www.kuaishou.com>      |
+//! | netease       | ✓        | ✓      | <https://m.163.com>             |
+//! | reddit        | ✓        | -      | <https://www.reddit.com>        |
+//! | sspai         | ✓        | -      | <https://sspai.com>             |
+//! | tencent       | ✓        | ✓      | <https://news.qq.com>           |
+//! | thepaper      | ✓        | -      | <https://www.thepaper.cn>       |
+//! | tieba         | ✓        | -      | <https://www.tieba.com>         |
+//! | toutiao       | ✓        | ✓      | <https://www.toutiao.com>       |
+//! | v2ex          | ✓        | -      | <https://www.v2ex.com>          |
+//! | weibo         | ✓        | ✓      | <https://weibo.com>             |
+//! | zhihu         | ✓        | ✓      | <https://www.zhihu.com>         |
 //!
 //! #### 1. Create AsyncClient
-//! 
+//!
 //! ```rust
 //! use std::time::Duration;
-//! use trending::{client::AsyncClient, errors::Result};
-//! 
+//! use trending::client::{AsyncClient, ClientOptions};
+//!
+//! # fn main() -> trending::errors::Result<()> {
 //! // new with default options
 //! let client = AsyncClient::new();
-//! 
+//!
 //! // or new with custom options
 //! let options = ClientOptions::new().with_timeout(Duration::from_secs(5));
-//! let client = AsyncClient::new_with_options(options);
+//! let client = AsyncClient::new_with_options(options)?;
+//! # Ok(())
+//! # }
 //! ```
-//! 
+//!
 //! #### 2. Trending Query
-//! 
-//! ```rust
+//!
+//! ```rust,no_run
+//! # use trending::client::AsyncClient;
+//! # async fn run() -> trending::errors::Result<()> {
+//! # let client = AsyncClient::new();
 //! // receive 29 trendings from zhihu
 //! let res = client.trending_zhihu().await?;
 //! println!("receive {} trendings from {}", res.result.len(), res.platform);
-//! 
+//!
 //! // 0 -> 货车司机往黄山拉玻璃，因两根松木框架被罚五千元，为何黄山对松木管控这么严格？
 //! // ...
 //! for (index, trending) in res.result.iter().enumerate() {
 //!   println!("{:2} -> {}", index, trending.title);
 //! }
+//! # Ok(())
+//! # }
 //! ```
-//! 
-//! #### 2. Search Query
-//! 
-//! ```rust
+//!
+//! #### 3. Search Query
+//!
+//! ```rust,no_run
+//! # use trending::{client::AsyncClient, common::SearchReq};
+//! # async fn run() -> trending::errors::Result<()> {
+//! # let client = AsyncClient::new();
 //! let req = SearchReq::new("ELON");
-//! 
-//! let res = client.search_tecent(&req).await?;
+//!
+//! let res = client.search_tencent(&req).await?;
 //! // receive 20 trendings from tencent
 //! println!("receive {} searches from {}", res.result.len(), res.platform);
-//! 
+//!
 //! // 0 -> 1万亿美元年薪，Elon Musk创纪录
 //! // ...
 //! for (index, search) in res.result.iter().enumerate() {
 //!   println!("{:2} -> {}", index, search.title);
 //! }
+//! # Ok(())
+//! # }
 //! ```
 
-
+mod baidu;
+mod bilibili;
 pub mod client;
 pub mod common;
+mod csdn;
+mod douban;
+mod douyin;
 pub mod errors;
+mod github;
+mod hackernews;
 mod hupu;
+mod huxiu;
+mod ifeng;
+mod ithome;
+mod juejin;
+mod kr36;
+mod kuaishou;
 mod netease;
+mod reddit;
+mod sspai;
 mod tencent;
+mod thepaper;
 mod tieba;
 mod toutiao;
+mod v2ex;
 mod weibo;
 mod zhihu;
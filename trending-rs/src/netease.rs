@@ -1,46 +1,66 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "blocking")]
 use crate::common::block_http_get;
 use crate::{
   common::{
-    EmptyType, MediaData, PageParam, PlatformType, SearchReq, SearchRes, SearchesRes, TrendingRes,
-    TrendingsRes, http_get,
+    EmptyType, MediaData, PlatformType, SearchFilter, SearchReq, SearchRes, SearchesRes,
+    TrendingRes, TrendingsRes, http_get, parse_datetime,
   },
-  errors::Result,
+  errors::{Result, TrendingError},
 };
 
-pub const TRENDING_ENDPOINT: &'static str = "https://m.163.com/fe/api/hot/news/flow";
-pub const SEARCH_ENDPOINT: &'static str =
+pub const TRENDING_ENDPOINT: &str = "https://m.163.com/fe/api/hot/news/flow";
+pub const SEARCH_ENDPOINT: &str =
   "https://gw.m.163.com/nc/api/v1/pc-wap/search?from=wap&needPcUrl=true";
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, NeteaseTrendingRes>(client, TRENDING_ENDPOINT, None, None, None)
-    .await
-    .map(|r| r.into())
+/// Filters of [`SearchReq`] honored by the search.
+pub const SEARCH_FILTERS: &[SearchFilter] = &[SearchFilter::Size];
+
+/// Page size used when the search request has no size.
+const SEARCH_PAGE_SIZE: u32 = 20;
+
+fn page_size(req: &SearchReq) -> u32 {
+  req.size.unwrap_or(SEARCH_PAGE_SIZE)
 }
 
-pub async fn search(client: &AsyncClient, req: &SearchReq) -> Result<SearchesRes> {
-  http_get::<NeteaseSearchReq, EmptyType, NeteaseSearchRes>(
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, NeteaseTrendingRes>(
     client,
-    SEARCH_ENDPOINT,
+    TRENDING_ENDPOINT,
+    headers,
     None,
-    Some(&req.into()),
     None,
   )
   .await
   .map(|r| r.into())
 }
 
+pub async fn search(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  http_get::<NeteaseSearchReq, EmptyType, NeteaseSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .and_then(|r| SearchesRes::from(r).with_next_page(req, page_size(req)))
+}
+
 #[cfg(feature = "blocking")]
-pub fn blocking_trending(client: &BlockClient) -> Result<TrendingsRes> {
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
   block_http_get::<EmptyType, EmptyType, NeteaseTrendingRes>(
     client,
     TRENDING_ENDPOINT,
-    None,
+    headers,
     None,
     None,
   )
@@ -48,15 +68,19 @@ pub fn blocking_trending(client: &BlockClient) -> Result<TrendingsRes> {
 }
 
 #[cfg(feature = "blocking")]
-pub fn blocking_search(client: &BlockClient, req: &SearchReq) -> Result<SearchesRes> {
+pub fn blocking_search(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
   block_http_get::<NeteaseSearchReq, EmptyType, NeteaseSearchRes>(
     client,
     SEARCH_ENDPOINT,
-    None,
-    Some(&req.into()),
+    headers,
+    Some(&req.try_into()?),
     None,
   )
-  .map(|r| r.into())
+  .and_then(|r| SearchesRes::from(r).with_next_page(req, page_size(req)))
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -85,7 +109,7 @@ impl From<NeteaseTrendingNews> for TrendingRes {
     Self {
       title: value.title,
       url: value.url,
-      trend: None,
+      ..Default::default()
     }
   }
 }
@@ -114,27 +138,24 @@ struct NeteaseSearchReq<'a> {
   query_id: Option<&'a str>,
 }
 
-impl<'a> NeteaseSearchReq<'a> {
-  fn convert_page_param(page: &PageParam) -> u32 {
-    match page {
-      PageParam::First => 0,
-      PageParam::Other(page) => *page,
-    }
-  }
-}
+impl<'a> TryFrom<&'a SearchReq> for NeteaseSearchReq<'a> {
+  type Error = TrendingError;
 
-impl<'a> From<&'a SearchReq> for NeteaseSearchReq<'a> {
-  fn from(value: &'a SearchReq) -> Self {
-    Self {
+  fn try_from(value: &'a SearchReq) -> Result<Self> {
+    Ok(Self {
       query: value.keyword.as_ref(),
-      page: value.page.as_ref().map(Self::convert_page_param),
+      page: value
+        .page
+        .as_ref()
+        .map(|p| p.number(PlatformType::Netease, page_size(value)))
+        .transpose()?,
       size: value.size,
       query_id: if value.page.is_none() && value.size.is_none() {
         None
       } else {
         Some("DEFAULT")
       },
-    }
+    })
   }
 }
 
@@ -170,10 +191,16 @@ impl From<NeteaseSearchResult> for SearchRes {
     Self {
       title: value.title.replace("<em>", "").replace("</em>", ""),
       url: value.url,
-      time: Some(1),
+      time: parse_datetime(&value.time),
       medias: value
         .img_url
-        .map(|s| s.into_iter().map(|m| MediaData::new_image(m)).collect()),
+        .map(|s| s.into_iter().map(MediaData::new_image).collect()),
+      desc: None,
+      author: None,
+      likes: None,
+      comments: None,
+      kind: None,
+      category: None,
     }
   }
 }
@@ -182,6 +209,8 @@ impl From<NeteaseSearchRes> for SearchesRes {
   fn from(value: NeteaseSearchRes) -> Self {
     Self {
       platform: PlatformType::Netease,
+      ignored: Vec::new(),
+      next: None,
       result: value.data.result.into_iter().map(|r| r.into()).collect(),
     }
   }
@@ -0,0 +1,159 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{
+  Client as AsyncClient,
+  header::{HeaderMap, HeaderValue, USER_AGENT},
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{EmptyType, MediaData, PlatformType, TrendingReq, TrendingRes, TrendingsRes, http_get},
+  errors::{Result, UnsupportedBoardSnafu},
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://www.reddit.com/r";
+
+/// Subreddit used when the request has no board.
+pub const DEFAULT_SUBREDDIT: &str = "popular";
+
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, RedditRes>(
+    client,
+    &hot_url(req)?,
+    Some(with_user_agent(headers)),
+    None,
+    None,
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, RedditRes>(
+    client,
+    &hot_url(req)?,
+    Some(with_user_agent(headers)),
+    None,
+    None,
+  )
+  .map(|r| r.into())
+}
+
+/// The board goes into the url path, so only subreddit names made of letters,
+/// digits and underscores are accepted.
+fn hot_url(req: &TrendingReq) -> Result<String> {
+  let subreddit = req.board.as_deref().unwrap_or(DEFAULT_SUBREDDIT);
+  let valid = (1..=21).contains(&subreddit.len())
+    && subreddit
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '_');
+  if !valid {
+    return UnsupportedBoardSnafu {
+      platform: PlatformType::Reddit.to_str(),
+      board: subreddit,
+    }
+    .fail();
+  }
+  Ok(format!(
+    "{}/{}/hot.json?limit=50&raw_json=1",
+    TRENDING_ENDPOINT, subreddit
+  ))
+}
+
+/// Reddit throttles requests without a user agent.
+fn with_user_agent(headers: Option<HeaderMap>) -> HeaderMap {
+  let mut headers = headers.unwrap_or_default();
+  headers
+    .entry(USER_AGENT)
+    .or_insert(HeaderValue::from_static("trending"));
+  headers
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct RedditRes {
+  #[serde(rename = "data")]
+  data: RedditListing,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct RedditListing {
+  #[serde(rename = "children", skip_serializing_if = "Vec::is_empty", default)]
+  children: Vec<RedditChild>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct RedditChild {
+  #[serde(rename = "data")]
+  data: RedditPost,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct RedditPost {
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "permalink")]
+  permalink: String,
+
+  #[serde(rename = "score", skip_serializing_if = "Option::is_none")]
+  score: Option<i64>,
+
+  #[serde(rename = "author", skip_serializing_if = "Option::is_none")]
+  author: Option<String>,
+
+  #[serde(rename = "created_utc", skip_serializing_if = "Option::is_none")]
+  created_utc: Option<f64>,
+
+  #[serde(rename = "thumbnail", skip_serializing_if = "Option::is_none")]
+  thumbnail: Option<String>,
+
+  #[serde(
+    rename = "subreddit_name_prefixed",
+    skip_serializing_if = "Option::is_none"
+  )]
+  subreddit: Option<String>,
+}
+
+impl From<RedditPost> for TrendingRes {
+  fn from(value: RedditPost) -> Self {
+    Self {
+      title: value.title,
+      url: format!("https://www.reddit.com{}", value.permalink),
+      trend: value.score.map(|s| s.to_string()),
+      // thumbnail is `self`, `default` or `nsfw` when there is no image
+      medias: value
+        .thumbnail
+        .filter(|t| t.starts_with("http"))
+        .map(|t| vec![MediaData::new_image(t)]),
+      desc: value.subreddit,
+      author: value.author,
+      time: value.created_utc.map(|t| t as u64),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<RedditRes> for TrendingsRes {
+  fn from(value: RedditRes) -> Self {
+    Self {
+      platform: PlatformType::Reddit,
+      result: value
+        .data
+        .children
+        .into_iter()
+        .map(|c| c.data.into())
+        .collect(),
+    }
+  }
+}
@@ -0,0 +1,121 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://sspai.com/api/v1/article/tag/page/get";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<SspaiTrendingReq, EmptyType, SspaiRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&SspaiTrendingReq::default()),
+    None,
+  )
+  .await
+  .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<SspaiTrendingReq, EmptyType, SspaiRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&SspaiTrendingReq::default()),
+    None,
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SspaiTrendingReq {
+  #[serde(rename = "limit")]
+  limit: u32,
+
+  #[serde(rename = "offset")]
+  offset: u32,
+
+  #[serde(rename = "tag")]
+  tag: &'static str,
+}
+
+impl Default for SspaiTrendingReq {
+  fn default() -> Self {
+    Self {
+      limit: 30,
+      offset: 0,
+      tag: "热门文章",
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SspaiRes {
+  #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
+  data: Vec<SspaiArticle>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SspaiArticle {
+  #[serde(rename = "id")]
+  id: u64,
+
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "banner", skip_serializing_if = "Option::is_none")]
+  banner: Option<String>,
+
+  #[serde(rename = "summary", skip_serializing_if = "Option::is_none")]
+  summary: Option<String>,
+
+  #[serde(rename = "released_time", skip_serializing_if = "Option::is_none")]
+  released_time: Option<u64>,
+
+  #[serde(rename = "like_count", skip_serializing_if = "Option::is_none")]
+  like_count: Option<u64>,
+
+  #[serde(rename = "author", skip_serializing_if = "Option::is_none")]
+  author: Option<SspaiAuthor>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SspaiAuthor {
+  #[serde(rename = "nickname")]
+  nickname: String,
+}
+
+impl From<SspaiArticle> for TrendingRes {
+  fn from(value: SspaiArticle) -> Self {
+    Self {
+      title: value.title,
+      url: format!("https://sspai.com/post/{}", value.id),
+      trend: value.like_count.map(|l| l.to_string()),
+      medias: not_empty_str(value.banner).map(|b| vec![MediaData::new_image(b)]),
+      desc: not_empty_str(value.summary),
+      author: value.author.map(|a| a.nickname),
+      time: value.released_time,
+      ..Default::default()
+    }
+  }
+}
+
+impl From<SspaiRes> for TrendingsRes {
+  fn from(value: SspaiRes) -> Self {
+    Self {
+      platform: PlatformType::Sspai,
+      result: value.data.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
@@ -1,6 +1,6 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "blocking")]
@@ -9,67 +9,102 @@ use crate::common::block_http_get;
 use crate::common::block_http_post;
 use crate::{
   common::{
-    EmptyType, HttpBody, MediaData, PageParam, PlatformType, SearchReq, SearchRes, SearchesRes,
-    TrendingRes, TrendingsRes, http_get, http_post,
+    EmptyType, HttpBody, MediaData, PlatformType, SearchFilter, SearchReq, SearchRes, SearchType,
+    SearchesRes, TrendingReq, TrendingRes, TrendingsRes, http_get, http_post, not_empty_str,
   },
-  errors::Result,
+  errors::{Result, TrendingError},
 };
 
-pub const TRENDING_ENDPOINT: &'static str =
-  "https://r.inews.qq.com/gw/event/hot_ranking_list?page_size=30";
+pub const TRENDING_ENDPOINT: &str = "https://r.inews.qq.com/gw/event/hot_ranking_list";
 
-pub const SEARCH_ENDPOINT: &'static str = "https://i.news.qq.com/gw/pc_search/result";
+/// Page size used when the trending request has no size.
+const TRENDING_PAGE_SIZE: u32 = 30;
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, TencentTrendingRes>(client, TRENDING_ENDPOINT, None, None, None)
-    .await
-    .map(|r| r.into())
+pub const SEARCH_ENDPOINT: &str = "https://i.news.qq.com/gw/pc_search/result";
+
+/// Filters of [`SearchReq`] honored by the search.
+pub const SEARCH_FILTERS: &[SearchFilter] = &[SearchFilter::Kind, SearchFilter::Size];
+
+/// Page size used when the search request has no size.
+const SEARCH_PAGE_SIZE: u32 = 20;
+
+fn page_size(req: &SearchReq) -> u32 {
+  req.size.unwrap_or(SEARCH_PAGE_SIZE)
+}
+
+/// Hot ranking of tencent news, the board selects the `rank_id` of another rank
+/// list and the `offset`, `size` and `appver` params are passed through.
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  http_get::<TencentTrendingReq, EmptyType, TencentTrendingRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .map(|r| r.into())
 }
 
-pub async fn search(client: &AsyncClient, req: &SearchReq) -> Result<SearchesRes> {
+pub async fn search(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
   http_post::<EmptyType, TencentSearchReq, TencentSearchRes>(
     client,
     SEARCH_ENDPOINT,
+    headers,
     None,
-    None,
-    Some(HttpBody::form(&req.into())),
+    Some(HttpBody::form(&req.try_into()?)),
   )
   .await
-  .map(|r| r.into())
+  .and_then(|r| SearchesRes::from(r).with_next_page(req, page_size(req)))
 }
 
 #[cfg(feature = "blocking")]
-pub fn blocking_trending(client: &BlockClient) -> Result<TrendingsRes> {
-  block_http_get::<EmptyType, EmptyType, TencentTrendingRes>(
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  block_http_get::<TencentTrendingReq, EmptyType, TencentTrendingRes>(
     client,
     TRENDING_ENDPOINT,
-    None,
-    None,
+    headers,
+    Some(&req.try_into()?),
     None,
   )
   .map(|r| r.into())
 }
 
 #[cfg(feature = "blocking")]
-pub fn blocking_search(client: &BlockClient, req: &SearchReq) -> Result<SearchesRes> {
+pub fn blocking_search(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
   block_http_post::<EmptyType, TencentSearchReq, TencentSearchRes>(
     client,
     SEARCH_ENDPOINT,
+    headers,
     None,
-    None,
-    Some(HttpBody::form(&req.into())),
+    Some(HttpBody::form(&req.try_into()?)),
   )
-  .map(|r| r.into())
+  .and_then(|r| SearchesRes::from(r).with_next_page(req, page_size(req)))
 }
 
-#[allow(dead_code)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct TencentTrendingReq {
   #[serde(rename = "offset", skip_serializing_if = "Option::is_none")]
   offset: Option<u32>,
 
-  #[serde(rename = "page_size", skip_serializing_if = "Option::is_none")]
-  size: Option<u32>,
+  #[serde(rename = "page_size")]
+  size: u32,
 
   #[serde(rename = "appver", skip_serializing_if = "Option::is_none")]
   app_ver: Option<String>,
@@ -78,6 +113,21 @@ struct TencentTrendingReq {
   rank_id: Option<String>,
 }
 
+impl TryFrom<&TrendingReq> for TencentTrendingReq {
+  type Error = TrendingError;
+
+  fn try_from(value: &TrendingReq) -> Result<Self> {
+    Ok(Self {
+      offset: value.param_u32(PlatformType::Tencent, "offset")?,
+      size: value
+        .param_u32(PlatformType::Tencent, "size")?
+        .unwrap_or(TRENDING_PAGE_SIZE),
+      app_ver: value.param("appver").map(str::to_string),
+      rank_id: value.board.clone(),
+    })
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct TencentTrendingRes {
   #[serde(rename = "idlist", skip_serializing_if = "Vec::is_empty", default)]
@@ -109,8 +159,9 @@ impl From<TencentTrendingNews> for TrendingRes {
   fn from(value: TencentTrendingNews) -> Self {
     Self {
       title: value.title,
-      url: value.url.unwrap_or_else(String::new),
-      trend: value.ranking.map(|r| r.to_string()),
+      url: value.url.unwrap_or_default(),
+      rank: value.ranking,
+      ..Default::default()
     }
   }
 }
@@ -138,6 +189,8 @@ struct TencentSearchReq<'a> {
   #[serde(rename = "is_pc")]
   is_pc: u32,
 
+  /// Tab of the search page, `all` or the [`SearchType::as_str`] of the kind,
+  /// the values sent by the tabs of <https://news.qq.com/search>.
   #[serde(rename = "search_type")]
   search_type: &'a str,
 
@@ -157,26 +210,23 @@ struct TencentSearchReq<'a> {
   app_ver: Option<&'a str>,
 }
 
-impl<'a> TencentSearchReq<'a> {
-  fn convert_page_param(page: &PageParam) -> u32 {
-    match page {
-      PageParam::First => 0,
-      PageParam::Other(page) => *page,
-    }
-  }
-}
+impl<'a> TryFrom<&'a SearchReq> for TencentSearchReq<'a> {
+  type Error = TrendingError;
 
-impl<'a> From<&'a SearchReq> for TencentSearchReq<'a> {
-  fn from(value: &'a SearchReq) -> Self {
-    Self {
+  fn try_from(value: &'a SearchReq) -> Result<Self> {
+    Ok(Self {
       query: &value.keyword,
       is_pc: 1,
-      search_type: "all",
-      page: value.page.as_ref().map(Self::convert_page_param),
+      search_type: value.kind.as_ref().map_or("all", SearchType::as_str),
+      page: value
+        .page
+        .as_ref()
+        .map(|p| p.number(PlatformType::Tencent, page_size(value)))
+        .transpose()?,
       hippy_custom_version: None,
       search_count_limit: value.size,
       app_ver: None,
-    }
+    })
   }
 }
 
@@ -188,6 +238,7 @@ struct TencentSearchRes {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct TencentSearchList {
+  /// Section of the results, see [`TencentSearchList::kind`].
   #[serde(rename = "secType")]
   sec_type: u32,
 
@@ -214,6 +265,39 @@ struct TencentSearchNews {
 
   #[serde(rename = "thumbnails_qqnews", skip_serializing_if = "Option::is_none")]
   thumbnail: Option<Vec<String>>,
+
+  #[serde(rename = "video_channel", skip_serializing_if = "Option::is_none")]
+  video_channel: Option<TencentVideoChannel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TencentVideoChannel {
+  #[serde(rename = "video", skip_serializing_if = "Option::is_none")]
+  video: Option<TencentVideo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TencentVideo {
+  #[serde(rename = "vid", skip_serializing_if = "Option::is_none")]
+  vid: Option<String>,
+
+  #[serde(rename = "playurl", skip_serializing_if = "Option::is_none")]
+  play_url: Option<String>,
+}
+
+impl TencentSearchList {
+  /// Kind of the section, tencent does not document `secType` so the mapping
+  /// follows the sections returned for the tabs of <https://news.qq.com/search>:
+  /// `0` and `1` are articles, `2` and `4` short and long videos, `3` accounts.
+  /// Unknown sections keep their results without a kind.
+  fn kind(&self) -> Option<SearchType> {
+    match self.sec_type {
+      0 | 1 => Some(SearchType::News),
+      2 | 4 => Some(SearchType::Video),
+      3 => Some(SearchType::Account),
+      _ => None,
+    }
+  }
 }
 
 impl From<TencentSearchNews> for SearchRes {
@@ -224,21 +308,81 @@ impl From<TencentSearchNews> for SearchRes {
       time: Some(value.timestamp),
       medias: value
         .thumbnail
-        .map(|s| s.into_iter().map(|m| MediaData::new_image(m)).collect()),
+        .map(|s| s.into_iter().map(MediaData::new_image).collect()),
+      desc: None,
+      author: None,
+      likes: None,
+      comments: None,
+      kind: None,
+      category: None,
     }
   }
 }
 
+impl TencentSearchNews {
+  fn into_search(self, kind: Option<SearchType>) -> SearchRes {
+    let video = self
+      .video_channel
+      .as_ref()
+      .and_then(|c| c.video.as_ref())
+      .and_then(|v| {
+        not_empty_str(v.play_url.clone()).or_else(|| {
+          not_empty_str(v.vid.clone()).map(|vid| format!("https://v.qq.com/x/page/{vid}.html"))
+        })
+      });
+    let mut res: SearchRes = self.into();
+    if let Some(video) = video {
+      res
+        .medias
+        .get_or_insert_with(Vec::new)
+        .insert(0, MediaData::new_video(video));
+    }
+    res.kind = kind;
+    res
+  }
+}
+
 impl From<TencentSearchRes> for SearchesRes {
   fn from(value: TencentSearchRes) -> Self {
     Self {
       platform: PlatformType::Tencent,
+      ignored: Vec::new(),
+      next: None,
       result: value
         .list
         .into_iter()
-        .flat_map(|l| l.news_list.into_iter())
-        .map(|r| r.into())
+        .flat_map(|l| {
+          let kind = l.kind();
+          l.news_list.into_iter().map(move |r| r.into_search(kind))
+        })
         .collect(),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::common::MediaType;
+
+  #[test]
+  fn search_res_maps_sections() {
+    let res: TencentSearchRes =
+      serde_json::from_str(include_str!("../tests/fixtures/tencent_search.json")).unwrap();
+    let res = SearchesRes::from(res);
+    assert_eq!(res.result.len(), 3);
+
+    let news = &res.result[0];
+    assert_eq!(news.kind, Some(SearchType::News));
+    assert_eq!(news.time, Some(1760000000));
+
+    let video = &res.result[1];
+    assert_eq!(video.kind, Some(SearchType::Video));
+    let medias = video.medias.as_ref().unwrap();
+    assert_eq!(medias[0].url, "https://v.qq.com/x/page/v0001abcd.html");
+    assert_eq!(medias[0].kind, MediaType::Video);
+    assert_eq!(medias[1].kind, MediaType::Image);
+
+    assert_eq!(res.result[2].kind, None);
+  }
+}
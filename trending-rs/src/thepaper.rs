@@ -0,0 +1,103 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{
+    EmptyType, MediaData, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str,
+  },
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://cache.thepaper.cn/contentapi/wwwIndex/rightSidebar";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, ThepaperRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, ThepaperRes>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    None,
+  )
+  .map(|r| r.into())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ThepaperRes {
+  #[serde(rename = "data")]
+  data: ThepaperData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ThepaperData {
+  #[serde(rename = "hotNews", skip_serializing_if = "Vec::is_empty", default)]
+  hot_news: Vec<ThepaperNews>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ThepaperNews {
+  #[serde(rename = "contId")]
+  cont_id: String,
+
+  #[serde(rename = "name")]
+  name: String,
+
+  #[serde(rename = "summary", skip_serializing_if = "Option::is_none")]
+  summary: Option<String>,
+
+  #[serde(rename = "pic", skip_serializing_if = "Option::is_none")]
+  pic: Option<String>,
+
+  #[serde(rename = "praiseTimes", skip_serializing_if = "Option::is_none")]
+  praise_times: Option<String>,
+
+  #[serde(rename = "pubTimeLong", skip_serializing_if = "Option::is_none")]
+  pub_time_long: Option<u64>,
+
+  #[serde(rename = "nodeInfo", skip_serializing_if = "Option::is_none")]
+  node_info: Option<ThepaperNode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ThepaperNode {
+  #[serde(rename = "name")]
+  name: String,
+}
+
+impl From<ThepaperNews> for TrendingRes {
+  fn from(value: ThepaperNews) -> Self {
+    Self {
+      title: value.name,
+      url: format!(
+        "https://www.thepaper.cn/newsDetail_forward_{}",
+        value.cont_id
+      ),
+      trend: not_empty_str(value.praise_times),
+      medias: not_empty_str(value.pic).map(|p| vec![MediaData::new_image(p)]),
+      desc: not_empty_str(value.summary),
+      author: value.node_info.map(|n| n.name),
+      // milliseconds
+      time: value.pub_time_long.map(|t| t / 1000),
+      ..Default::default()
+    }
+  }
+}
+
+impl From<ThepaperRes> for TrendingsRes {
+  fn from(value: ThepaperRes) -> Self {
+    Self {
+      platform: PlatformType::Thepaper,
+      result: value.data.hot_news.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
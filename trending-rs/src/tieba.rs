@@ -1,27 +1,106 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "blocking")]
 use crate::common::block_http_get;
 use crate::{
-  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get},
+  common::{
+    EmptyType, MediaData, PlatformType, TopicRes, TrendingReq, TrendingRes, TrendingsRes, de_count,
+    http_get, not_empty_str,
+  },
   errors::Result,
 };
 
-pub const TRENDING_ENDPOINT: &'static str = "https://tieba.baidu.com/hottopic/browse/topicList";
+pub const TRENDING_ENDPOINT: &str = "https://tieba.baidu.com/hottopic/browse/topicList";
+pub const FORUM_ENDPOINT: &str =
+  "https://tieba.baidu.com/mg/f/getFrsData?rn=30&pn=1&is_good=0&cid=0&sort_type=0&default_pro=1";
+pub const TOPIC_ENDPOINT: &str = "https://tieba.baidu.com/hottopic/browse/hottopic";
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, TiebaRes>(client, TRENDING_ENDPOINT, None, None, None)
+/// Hot topics of tieba, or the hot threads of one forum when the request has
+/// a board, which is the forum name such as `rust`.
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  match req.board.as_deref() {
+    Some(forum) => http_get::<TiebaForumReq, EmptyType, TiebaForumRes>(
+      client,
+      FORUM_ENDPOINT,
+      headers,
+      Some(&TiebaForumReq { kw: forum }),
+      None,
+    )
     .await
-    .map(|r| r.into())
+    .map(|r| r.into()),
+    None => {
+      http_get::<EmptyType, EmptyType, TiebaRes>(client, TRENDING_ENDPOINT, headers, None, None)
+        .await
+        .map(|r| r.into())
+    }
+  }
+}
+
+/// Description, discussion count and top posts of a hot topic, the id is the
+/// `topic_id` of the topic url.
+pub async fn topic(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  topic_id: u64,
+) -> Result<TopicRes> {
+  http_get::<TiebaTopicReq, EmptyType, TiebaTopicRes>(
+    client,
+    TOPIC_ENDPOINT,
+    headers,
+    Some(&TiebaTopicReq { topic_id }),
+    None,
+  )
+  .await
+  .map(|r| r.into())
 }
 
 #[cfg(feature = "blocking")]
-pub fn blocking_trending(client: &BlockClient) -> Result<TrendingsRes> {
-  block_http_get::<EmptyType, EmptyType, TiebaRes>(client, TRENDING_ENDPOINT, None, None, None)
-    .map(|r| r.into())
+pub fn blocking_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  match req.board.as_deref() {
+    Some(forum) => block_http_get::<TiebaForumReq, EmptyType, TiebaForumRes>(
+      client,
+      FORUM_ENDPOINT,
+      headers,
+      Some(&TiebaForumReq { kw: forum }),
+      None,
+    )
+    .map(|r| r.into()),
+    None => block_http_get::<EmptyType, EmptyType, TiebaRes>(
+      client,
+      TRENDING_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
+    .map(|r| r.into()),
+  }
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_topic(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  topic_id: u64,
+) -> Result<TopicRes> {
+  block_http_get::<TiebaTopicReq, EmptyType, TiebaTopicRes>(
+    client,
+    TOPIC_ENDPOINT,
+    headers,
+    Some(&TiebaTopicReq { topic_id }),
+    None,
+  )
+  .map(|r| r.into())
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -52,6 +131,12 @@ struct TiebaItem {
 
   #[serde(rename = "discuss_num")]
   trend: u64,
+
+  #[serde(rename = "topic_desc", skip_serializing_if = "Option::is_none")]
+  desc: Option<String>,
+
+  #[serde(rename = "topic_pic", skip_serializing_if = "Option::is_none")]
+  pic: Option<String>,
 }
 
 impl From<TiebaItem> for TrendingRes {
@@ -60,6 +145,10 @@ impl From<TiebaItem> for TrendingRes {
       title: value.title,
       url: value.url,
       trend: Some(value.trend.to_string()),
+      medias: not_empty_str(value.pic).map(|p| vec![MediaData::new_image(p)]),
+      desc: not_empty_str(value.desc),
+      comments: Some(value.trend),
+      ..Default::default()
     }
   }
 }
@@ -67,7 +156,7 @@ impl From<TiebaItem> for TrendingRes {
 impl From<TiebaRes> for TrendingsRes {
   fn from(value: TiebaRes) -> Self {
     Self {
-      platform: PlatformType::Tencent,
+      platform: PlatformType::Tieba,
       result: value
         .data
         .bang_topic
@@ -78,3 +167,180 @@ impl From<TiebaRes> for TrendingsRes {
     }
   }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaForumReq<'a> {
+  #[serde(rename = "kw")]
+  kw: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaForumRes {
+  #[serde(rename = "data")]
+  data: TiebaForumData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaForumData {
+  #[serde(rename = "thread_list", skip_serializing_if = "Vec::is_empty", default)]
+  thread_list: Vec<TiebaThread>,
+}
+
+/// A thread of a forum, also used for the top posts of a topic.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaThread {
+  #[serde(
+    rename = "tid",
+    alias = "thread_id",
+    deserialize_with = "de_count",
+    default
+  )]
+  tid: Option<u64>,
+
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+  desc: Option<String>,
+
+  #[serde(
+    rename = "author",
+    alias = "user",
+    skip_serializing_if = "Option::is_none"
+  )]
+  author: Option<TiebaAuthor>,
+
+  #[serde(rename = "create_time", deserialize_with = "de_count", default)]
+  create_time: Option<u64>,
+
+  #[serde(rename = "view_num", deserialize_with = "de_count", default)]
+  view_num: Option<u64>,
+
+  #[serde(rename = "reply_num", deserialize_with = "de_count", default)]
+  reply_num: Option<u64>,
+
+  #[serde(rename = "media", skip_serializing_if = "Vec::is_empty", default)]
+  media: Vec<TiebaMedia>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaAuthor {
+  #[serde(rename = "show_nickname", alias = "name_show")]
+  name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaMedia {
+  #[serde(rename = "big_pic", skip_serializing_if = "Option::is_none")]
+  big_pic: Option<String>,
+}
+
+impl From<TiebaThread> for TrendingRes {
+  fn from(value: TiebaThread) -> Self {
+    let medias: Vec<MediaData> = value
+      .media
+      .into_iter()
+      .filter_map(|m| not_empty_str(m.big_pic))
+      .map(MediaData::new_image)
+      .collect();
+    Self {
+      title: value.title,
+      url: format!(
+        "https://tieba.baidu.com/p/{}",
+        value.tid.unwrap_or_default()
+      ),
+      trend: value.reply_num.map(|r| r.to_string()),
+      medias: if medias.is_empty() {
+        None
+      } else {
+        Some(medias)
+      },
+      desc: not_empty_str(value.desc),
+      author: value.author.map(|a| a.name),
+      time: value.create_time,
+      reads: value.view_num,
+      comments: value.reply_num,
+      ..Default::default()
+    }
+  }
+}
+
+impl From<TiebaForumRes> for TrendingsRes {
+  fn from(value: TiebaForumRes) -> Self {
+    Self {
+      platform: PlatformType::Tieba,
+      result: value
+        .data
+        .thread_list
+        .into_iter()
+        .filter(|r| r.tid.is_some())
+        .map(|r| r.into())
+        .collect(),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaTopicReq {
+  #[serde(rename = "topic_id")]
+  topic_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaTopicRes {
+  #[serde(rename = "data")]
+  data: TiebaTopicData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaTopicData {
+  #[serde(rename = "topic_info")]
+  topic_info: TiebaTopicInfo,
+
+  #[serde(rename = "relate_thread", skip_serializing_if = "Option::is_none")]
+  relate_thread: Option<TiebaForumData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TiebaTopicInfo {
+  #[serde(rename = "topic_id", deserialize_with = "de_count", default)]
+  topic_id: Option<u64>,
+
+  #[serde(rename = "topic_name")]
+  topic_name: String,
+
+  #[serde(rename = "topic_desc", skip_serializing_if = "Option::is_none")]
+  topic_desc: Option<String>,
+
+  #[serde(rename = "discuss_num", deserialize_with = "de_count", default)]
+  discuss_num: Option<u64>,
+
+  #[serde(rename = "topic_image", skip_serializing_if = "Option::is_none")]
+  topic_image: Option<String>,
+}
+
+impl From<TiebaTopicRes> for TopicRes {
+  fn from(value: TiebaTopicRes) -> Self {
+    let info = value.data.topic_info;
+    Self {
+      platform: PlatformType::Tieba,
+      url: format!(
+        "https://tieba.baidu.com/hottopic/browse/hottopic?topic_id={}",
+        info.topic_id.unwrap_or_default()
+      ),
+      title: info.topic_name,
+      desc: not_empty_str(info.topic_desc),
+      discussions: info.discuss_num,
+      medias: not_empty_str(info.topic_image).map(|i| vec![MediaData::new_image(i)]),
+      posts: value
+        .data
+        .relate_thread
+        .map(|r| r.thread_list)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|r| r.tid.is_some())
+        .map(|r| r.into())
+        .collect(),
+    }
+  }
+}
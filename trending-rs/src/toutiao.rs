@@ -1,30 +1,72 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "blocking")]
 use crate::common::block_http_get;
 use crate::{
-  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str},
-  errors::Result,
+  common::{
+    EmptyType, MediaData, PageParam, PlatformType, SearchFilter, SearchReq, SearchRes, SearchesRes,
+    TrendingRes, TrendingsRes, de_count, http_get, not_empty_str,
+  },
+  errors::{Result, TrendingError},
 };
 
-pub const TRENDING_ENDPOINT: &'static str =
+pub const TRENDING_ENDPOINT: &str =
   "https://www.toutiao.com/hot-event/hot-board/?origin=toutiao_pc";
+pub const SEARCH_ENDPOINT: &str = "https://www.toutiao.com/api/search/content/?aid=24&app_name=web_search&format=json&cur_tab=1&pd=information&from=search_tab";
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, TouTiaoRes>(client, TRENDING_ENDPOINT, None, None, None)
+/// Filters of [`SearchReq`] honored by the search.
+pub const SEARCH_FILTERS: &[SearchFilter] = &[SearchFilter::Size];
+
+/// Page size used when the search request has no size.
+const SEARCH_PAGE_SIZE: u32 = 20;
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, TouTiaoRes>(client, TRENDING_ENDPOINT, headers, None, None)
     .await
     .map(|r| r.into())
 }
 
+pub async fn search(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  http_get::<TouTiaoSearchReq, EmptyType, TouTiaoSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .map(|r| r.into())
+}
+
 #[cfg(feature = "blocking")]
-pub fn blocking_trending(client: &BlockClient) -> Result<TrendingsRes> {
-  block_http_get::<EmptyType, EmptyType, TouTiaoRes>(client, TRENDING_ENDPOINT, None, None, None)
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, TouTiaoRes>(client, TRENDING_ENDPOINT, headers, None, None)
     .map(|r| r.into())
 }
 
+#[cfg(feature = "blocking")]
+pub fn blocking_search(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  block_http_get::<TouTiaoSearchReq, EmptyType, TouTiaoSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .map(|r| r.into())
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct TouTiaoRes {
   #[serde(rename = "data")]
@@ -52,6 +94,7 @@ impl From<TouTiaoData> for TrendingRes {
       title: value.title,
       url: value.url,
       trend: not_empty_str(value.hot_value),
+      ..Default::default()
     }
   }
 }
@@ -63,9 +106,161 @@ impl From<TouTiaoRes> for TrendingsRes {
       result: value
         .data
         .into_iter()
-        .chain(value.fixed_top_data.into_iter())
+        .chain(value.fixed_top_data)
         .map(|r| r.into())
         .collect(),
     }
   }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TouTiaoSearchReq<'a> {
+  #[serde(rename = "keyword")]
+  keyword: &'a str,
+
+  #[serde(rename = "offset")]
+  offset: u32,
+
+  #[serde(rename = "count")]
+  count: u32,
+}
+
+impl<'a> TryFrom<&'a SearchReq> for TouTiaoSearchReq<'a> {
+  type Error = TrendingError;
+
+  fn try_from(value: &'a SearchReq) -> Result<Self> {
+    let count = value.size.unwrap_or(SEARCH_PAGE_SIZE);
+    Ok(Self {
+      keyword: &value.keyword,
+      // toutiao pages by offset instead of page number
+      offset: value
+        .page
+        .as_ref()
+        .map_or(Ok(0), |p| p.offset(PlatformType::Toutiao, count))?,
+      count,
+    })
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TouTiaoSearchRes {
+  #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
+  data: Vec<TouTiaoArticle>,
+
+  #[serde(rename = "has_more", deserialize_with = "de_count", default)]
+  has_more: Option<u64>,
+
+  /// Offset of the next page.
+  #[serde(rename = "offset", deserialize_with = "de_count", default)]
+  offset: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TouTiaoArticle {
+  /// Missing for cards that are not articles, such as related searches.
+  #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+  title: Option<String>,
+
+  #[serde(rename = "article_url", skip_serializing_if = "Option::is_none")]
+  url: Option<String>,
+
+  #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
+  source: Option<String>,
+
+  #[serde(
+    rename = "publish_time",
+    deserialize_with = "de_count",
+    skip_serializing_if = "Option::is_none",
+    default
+  )]
+  publish_time: Option<u64>,
+
+  #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+  desc: Option<String>,
+
+  #[serde(
+    rename = "comment_count",
+    deserialize_with = "de_count",
+    skip_serializing_if = "Option::is_none",
+    default
+  )]
+  comment_count: Option<u64>,
+
+  #[serde(rename = "image_list", skip_serializing_if = "Vec::is_empty", default)]
+  image_list: Vec<TouTiaoImage>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TouTiaoImage {
+  #[serde(rename = "url")]
+  url: String,
+}
+
+impl TouTiaoArticle {
+  /// Cards that are not articles, such as related searches, give `None`.
+  fn into_search(self) -> Option<SearchRes> {
+    Some(SearchRes {
+      title: not_empty_str(self.title)?,
+      url: not_empty_str(self.url)?,
+      time: self.publish_time,
+      medias: if self.image_list.is_empty() {
+        None
+      } else {
+        Some(
+          self
+            .image_list
+            .into_iter()
+            .map(|i| match i.url.strip_prefix("//") {
+              Some(url) => MediaData::new_image(format!("https://{url}")),
+              None => MediaData::new_image(i.url),
+            })
+            .collect(),
+        )
+      },
+      desc: not_empty_str(self.desc),
+      author: not_empty_str(self.source),
+      likes: None,
+      comments: self.comment_count,
+      kind: None,
+      category: None,
+    })
+  }
+}
+
+impl From<TouTiaoSearchRes> for SearchesRes {
+  fn from(value: TouTiaoSearchRes) -> Self {
+    Self {
+      platform: PlatformType::Toutiao,
+      ignored: Vec::new(),
+      next: match (value.has_more, value.offset) {
+        (Some(1..), Some(offset)) => Some(PageParam::Offset(offset as u32)),
+        _ => None,
+      },
+      result: value
+        .data
+        .into_iter()
+        .filter_map(TouTiaoArticle::into_search)
+        .collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn article_counts_accept_strings() {
+    let article: TouTiaoArticle = serde_json::from_str(
+      r#"{"title": "rust", "publish_time": "1767196800", "comment_count": "12"}"#,
+    )
+    .unwrap();
+    assert_eq!(article.publish_time, Some(1767196800));
+    assert_eq!(article.comment_count, Some(12));
+
+    let article: TouTiaoArticle =
+      serde_json::from_str(r#"{"publish_time": 1767196800, "comment_count": null}"#).unwrap();
+    assert_eq!(article.publish_time, Some(1767196800));
+    assert_eq!(article.comment_count, None);
+  }
+}
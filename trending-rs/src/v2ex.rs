@@ -0,0 +1,86 @@
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::common::block_http_get;
+use crate::{
+  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str},
+  errors::Result,
+};
+
+pub const TRENDING_ENDPOINT: &str = "https://www.v2ex.com/api/topics/hot.json";
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, Vec<V2exTopic>>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(into_trendings)
+}
+
+#[cfg(feature = "blocking")]
+pub fn blocking_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, Vec<V2exTopic>>(
+    client,
+    TRENDING_ENDPOINT,
+    headers,
+    None,
+    None,
+  )
+  .map(into_trendings)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct V2exTopic {
+  #[serde(rename = "title")]
+  title: String,
+
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "replies", skip_serializing_if = "Option::is_none")]
+  replies: Option<u64>,
+
+  #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+  created: Option<u64>,
+
+  #[serde(rename = "member", skip_serializing_if = "Option::is_none")]
+  member: Option<V2exMember>,
+
+  #[serde(rename = "node", skip_serializing_if = "Option::is_none")]
+  node: Option<V2exNode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct V2exMember {
+  #[serde(rename = "username")]
+  username: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct V2exNode {
+  #[serde(rename = "title")]
+  title: Option<String>,
+}
+
+impl From<V2exTopic> for TrendingRes {
+  fn from(value: V2exTopic) -> Self {
+    Self {
+      title: value.title,
+      url: value.url,
+      trend: value.replies.map(|r| r.to_string()),
+      desc: value.node.and_then(|n| not_empty_str(n.title)),
+      author: value.member.map(|m| m.username),
+      time: value.created,
+      ..Default::default()
+    }
+  }
+}
+
+/// The api answers with a bare array of topics.
+fn into_trendings(value: Vec<V2exTopic>) -> TrendingsRes {
+  TrendingsRes {
+    platform: PlatformType::V2ex,
+    result: value.into_iter().map(|r| r.into()).collect(),
+  }
+}
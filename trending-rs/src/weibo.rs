@@ -1,75 +1,228 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
+use web_time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "blocking")]
 use crate::common::block_http_get;
 use crate::{
-  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str},
-  errors::Result,
+  common::{
+    EmptyType, MediaData, PageParam, PlatformType, SearchFilter, SearchReq, SearchRes, SearchSort,
+    SearchType, SearchesRes, TrendingReq, TrendingRes, TrendingsRes, de_count, http_get,
+    not_empty_str, parse_datetime, strip_html,
+  },
+  errors::{InvalidParamSnafu, PlainMessageSnafu, Result, TrendingError, UnsupportedBoardSnafu},
 };
 
-pub const TRENDING_ENDPOINT: &'static str =
+pub const TRENDING_ENDPOINT: &str =
   "https://newsapp.sina.cn/api/hotlist?newsId=HB-1-snhs/top_news_list-all";
+pub const REALTIME_ENDPOINT: &str = "https://weibo.com/ajax/side/hotSearch";
+pub const ENTERTAINMENT_ENDPOINT: &str = "https://weibo.com/ajax/statuses/entertainment";
+pub const TOPIC_ENDPOINT: &str =
+  "https://weibo.com/ajax/statuses/topic_band?sid=v_weibopro&category=all&page=1&count=50";
+pub const SEARCH_ENDPOINT: &str = "https://m.weibo.cn/api/container/getIndex";
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, WeiboRes>(client, TRENDING_ENDPOINT, None, None, None)
+/// Filters of [`SearchReq`] honored by the search.
+pub const SEARCH_FILTERS: &[SearchFilter] = &[SearchFilter::Sort, SearchFilter::Kind];
+
+/// Posts per search page, fixed by weibo.
+const SEARCH_PAGE_SIZE: u32 = 10;
+
+/// Boards of weibo, the first one is used when the request has no board.
+/// `entertainment` and `topic` need a logged in `cookie`, set it with
+/// [`crate::client::ClientOptions::with_platform_header`].
+pub const BOARDS: [&str; 4] = ["hot", "realtime", "entertainment", "topic"];
+
+pub async fn trending(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  match WeiboBoard::try_from(req)? {
+    WeiboBoard::Hot => {
+      http_get::<EmptyType, EmptyType, WeiboNewsRes>(client, TRENDING_ENDPOINT, headers, None, None)
+        .await
+        .map(|r| r.into())
+    }
+    WeiboBoard::Realtime => {
+      http_get::<EmptyType, EmptyType, WeiboRes>(client, REALTIME_ENDPOINT, headers, None, None)
+        .await
+        .and_then(|r| r.try_into())
+    }
+    WeiboBoard::Entertainment => http_get::<EmptyType, EmptyType, WeiboRes>(
+      client,
+      ENTERTAINMENT_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
     .await
-    .map(|r| r.into())
+    .and_then(|r| r.try_into()),
+    WeiboBoard::Topic => {
+      http_get::<EmptyType, EmptyType, WeiboTopicRes>(client, TOPIC_ENDPOINT, headers, None, None)
+        .await
+        .and_then(|r| r.try_into())
+    }
+  }
+}
+
+/// Searches posts through the mobile container api, the page size is fixed by
+/// weibo so the size of the request is ignored.
+pub async fn search(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  http_get::<WeiboSearchReq, EmptyType, WeiboSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .await
+  .map(|r| ignore_shadowed_sort(r.into(), req))
 }
 
 #[cfg(feature = "blocking")]
-pub fn block_trending(client: &BlockClient) -> Result<TrendingsRes> {
-  block_http_get::<EmptyType, EmptyType, WeiboRes>(client, TRENDING_ENDPOINT, None, None, None)
-    .map(|r| r.into())
+pub fn block_trending(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &TrendingReq,
+) -> Result<TrendingsRes> {
+  match WeiboBoard::try_from(req)? {
+    WeiboBoard::Hot => block_http_get::<EmptyType, EmptyType, WeiboNewsRes>(
+      client,
+      TRENDING_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
+    .map(|r| r.into()),
+    WeiboBoard::Realtime => block_http_get::<EmptyType, EmptyType, WeiboRes>(
+      client,
+      REALTIME_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
+    .and_then(|r| r.try_into()),
+    WeiboBoard::Entertainment => block_http_get::<EmptyType, EmptyType, WeiboRes>(
+      client,
+      ENTERTAINMENT_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
+    .and_then(|r| r.try_into()),
+    WeiboBoard::Topic => block_http_get::<EmptyType, EmptyType, WeiboTopicRes>(
+      client,
+      TOPIC_ENDPOINT,
+      headers,
+      None,
+      None,
+    )
+    .and_then(|r| r.try_into()),
+  }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct WeiboRes {
-  #[serde(rename = "status")]
-  status: i32,
+#[cfg(feature = "blocking")]
+pub fn blocking_search(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  block_http_get::<WeiboSearchReq, EmptyType, WeiboSearchRes>(
+    client,
+    SEARCH_ENDPOINT,
+    headers,
+    Some(&req.try_into()?),
+    None,
+  )
+  .map(|r| ignore_shadowed_sort(r.into(), req))
+}
+
+enum WeiboBoard {
+  Hot,
+  Realtime,
+  Entertainment,
+  Topic,
+}
+
+impl TryFrom<&TrendingReq> for WeiboBoard {
+  type Error = TrendingError;
+
+  fn try_from(value: &TrendingReq) -> Result<Self> {
+    match value.board.as_deref().unwrap_or(BOARDS[0]) {
+      "hot" => Ok(Self::Hot),
+      "realtime" => Ok(Self::Realtime),
+      "entertainment" => Ok(Self::Entertainment),
+      "topic" => Ok(Self::Topic),
+      other => UnsupportedBoardSnafu {
+        platform: PlatformType::Weibo.to_str(),
+        board: other,
+      }
+      .fail(),
+    }
+  }
+}
+
+fn search_url(word: &str) -> String {
+  format!(
+    "https://m.weibo.cn/search?containerid=100103type%3D1%26q%3D%23{}%23",
+    word
+  )
+}
+
+/// Answers of weibo.com without `data`, e.g. `{"ok": -100}` when a login is required.
+fn missing_data() -> TrendingError {
+  PlainMessageSnafu {
+    message: "weibo answered without data, the board needs a logged in cookie",
+  }
+  .build()
+}
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboNewsRes {
   #[serde(rename = "data")]
-  data: WeiboData,
+  data: WeiboNewsData,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct WeiboData {
+struct WeiboNewsData {
   #[serde(rename = "hotList", skip_serializing_if = "Vec::is_empty", default)]
-  hot_list: Vec<WeiboHot>,
+  hot_list: Vec<WeiboNews>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct WeiboHot {
+struct WeiboNews {
   #[serde(rename = "info")]
-  info: WeiboInfo,
+  info: WeiboNewsInfo,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct WeiboInfo {
+struct WeiboNewsInfo {
   #[serde(rename = "title")]
   title: String,
 
-  #[serde(rename = "hotValue")]
+  #[serde(rename = "hotValue", skip_serializing_if = "Option::is_none")]
   hot_value: Option<String>,
 }
 
-impl From<WeiboInfo> for TrendingRes {
-  fn from(value: WeiboInfo) -> Self {
+impl From<WeiboNewsInfo> for TrendingRes {
+  fn from(value: WeiboNewsInfo) -> Self {
     Self {
-      url: format!(
-        "https://m.weibo.cn/search?containerid=100103type%3D1%26q%3D%23{}%23",
-        &value.title
-      ),
+      url: search_url(&value.title),
       title: value.title,
       trend: not_empty_str(value.hot_value),
+      ..Default::default()
     }
   }
 }
 
-impl From<WeiboRes> for TrendingsRes {
-  fn from(value: WeiboRes) -> Self {
+impl From<WeiboNewsRes> for TrendingsRes {
+  fn from(value: WeiboNewsRes) -> Self {
     Self {
       platform: PlatformType::Weibo,
       result: value
@@ -81,3 +234,506 @@ impl From<WeiboRes> for TrendingsRes {
     }
   }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboRes {
+  #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
+  data: Option<WeiboData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboData {
+  // hot search answers with `realtime`, entertainment with `band_list`
+  #[serde(
+    rename = "realtime",
+    alias = "band_list",
+    skip_serializing_if = "Vec::is_empty",
+    default
+  )]
+  realtime: Vec<WeiboHot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboHot {
+  #[serde(rename = "word")]
+  word: String,
+
+  #[serde(rename = "num", skip_serializing_if = "Option::is_none")]
+  num: Option<u64>,
+
+  /// One of 新, 热, 沸 and 爆, empty for plain items.
+  #[serde(rename = "label_name", skip_serializing_if = "Option::is_none")]
+  label_name: Option<String>,
+
+  #[serde(rename = "is_ad", skip_serializing_if = "Option::is_none")]
+  is_ad: Option<u32>,
+}
+
+impl From<WeiboHot> for TrendingRes {
+  fn from(value: WeiboHot) -> Self {
+    Self {
+      url: search_url(&value.word),
+      title: value.word,
+      trend: value.num.map(|n| n.to_string()),
+      label: not_empty_str(value.label_name),
+      ..Default::default()
+    }
+  }
+}
+
+impl TryFrom<WeiboRes> for TrendingsRes {
+  type Error = TrendingError;
+
+  fn try_from(value: WeiboRes) -> Result<Self> {
+    Ok(Self {
+      platform: PlatformType::Weibo,
+      result: value
+        .data
+        .ok_or_else(missing_data)?
+        .realtime
+        .into_iter()
+        .filter(|r| r.is_ad.unwrap_or(0) == 0)
+        .map(|r| r.into())
+        .collect(),
+    })
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboTopicRes {
+  #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
+  data: Option<WeiboTopicData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboTopicData {
+  #[serde(rename = "statuses", skip_serializing_if = "Vec::is_empty", default)]
+  statuses: Vec<WeiboTopic>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboTopic {
+  #[serde(rename = "topic")]
+  topic: String,
+
+  #[serde(rename = "summary", skip_serializing_if = "Option::is_none")]
+  summary: Option<String>,
+
+  #[serde(rename = "read", skip_serializing_if = "Option::is_none")]
+  read: Option<u64>,
+
+  #[serde(rename = "mention", skip_serializing_if = "Option::is_none")]
+  mention: Option<u64>,
+
+  #[serde(rename = "images_url", skip_serializing_if = "Option::is_none")]
+  images_url: Option<String>,
+}
+
+impl From<WeiboTopic> for TrendingRes {
+  fn from(value: WeiboTopic) -> Self {
+    Self {
+      url: search_url(&value.topic),
+      title: value.topic,
+      trend: value.read.map(|r| r.to_string()),
+      medias: not_empty_str(value.images_url).map(|i| vec![MediaData::new_image(i)]),
+      desc: not_empty_str(value.summary),
+      reads: value.read,
+      comments: value.mention,
+      ..Default::default()
+    }
+  }
+}
+
+impl TryFrom<WeiboTopicRes> for TrendingsRes {
+  type Error = TrendingError;
+
+  fn try_from(value: WeiboTopicRes) -> Result<Self> {
+    Ok(Self {
+      platform: PlatformType::Weibo,
+      result: value
+        .data
+        .ok_or_else(missing_data)?
+        .statuses
+        .into_iter()
+        .map(|r| r.into())
+        .collect(),
+    })
+  }
+}
+
+/// The content type replaces the sort in the container, so a sort given along
+/// with a type is reported back as ignored.
+fn ignore_shadowed_sort(mut res: SearchesRes, req: &SearchReq) -> SearchesRes {
+  if req.kind.is_some() && req.sort.is_some() {
+    res.ignored.push(SearchFilter::Sort);
+  }
+  res
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboSearchReq {
+  #[serde(rename = "containerid")]
+  container_id: String,
+
+  #[serde(rename = "page_type")]
+  page_type: &'static str,
+
+  #[serde(rename = "page", skip_serializing_if = "Option::is_none")]
+  page: Option<u32>,
+}
+
+impl WeiboSearchReq {
+  /// Container type of the search, the content type takes precedence over the sort.
+  /// Accounts are rejected since their container holds user cards instead of posts.
+  fn search_type(req: &SearchReq) -> Result<u32> {
+    match (req.kind, req.sort) {
+      (Some(SearchType::Video), _) => Ok(64),
+      (Some(SearchType::Account), _) => InvalidParamSnafu {
+        platform: PlatformType::Weibo.to_str(),
+        param: "kind",
+        value: SearchType::Account.as_str(),
+      }
+      .fail(),
+      (_, Some(SearchSort::Newest)) => Ok(61),
+      (_, Some(SearchSort::Hottest)) => Ok(60),
+      _ => Ok(1),
+    }
+  }
+}
+
+impl TryFrom<&SearchReq> for WeiboSearchReq {
+  type Error = TrendingError;
+
+  fn try_from(value: &SearchReq) -> Result<Self> {
+    Ok(Self {
+      container_id: format!(
+        "100103type={}&q={}",
+        Self::search_type(value)?,
+        value.keyword
+      ),
+      page_type: "searchall",
+      // weibo counts pages from 1
+      page: value
+        .page
+        .as_ref()
+        .map(|p| p.number(PlatformType::Weibo, SEARCH_PAGE_SIZE))
+        .transpose()?
+        .map(|p| p.saturating_add(1)),
+    })
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboSearchRes {
+  #[serde(rename = "data")]
+  data: WeiboSearchData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboSearchData {
+  #[serde(rename = "cards", skip_serializing_if = "Vec::is_empty", default)]
+  cards: Vec<WeiboCard>,
+
+  #[serde(rename = "cardlistInfo", skip_serializing_if = "Option::is_none")]
+  cardlist_info: Option<WeiboCardlistInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboCardlistInfo {
+  /// Next page counted from 1, missing on the last page.
+  #[serde(rename = "page", deserialize_with = "de_count", default)]
+  page: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboCard {
+  #[serde(rename = "mblog", skip_serializing_if = "Option::is_none")]
+  mblog: Option<WeiboPost>,
+
+  /// Posts are either direct cards or grouped inside another card.
+  #[serde(rename = "card_group", skip_serializing_if = "Vec::is_empty", default)]
+  card_group: Vec<WeiboCard>,
+}
+
+impl WeiboCard {
+  fn into_posts(self) -> Vec<WeiboPost> {
+    self
+      .mblog
+      .into_iter()
+      .chain(self.card_group.into_iter().flat_map(WeiboCard::into_posts))
+      .collect()
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboPost {
+  #[serde(rename = "id")]
+  id: String,
+
+  #[serde(rename = "text")]
+  text: String,
+
+  /// Like `Sat Oct 18 10:00:00 +0800 2026`, recent posts may be relative.
+  #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+  created_at: Option<String>,
+
+  #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+  user: Option<WeiboUser>,
+
+  #[serde(rename = "attitudes_count", skip_serializing_if = "Option::is_none")]
+  attitudes_count: Option<u64>,
+
+  #[serde(rename = "comments_count", skip_serializing_if = "Option::is_none")]
+  comments_count: Option<u64>,
+
+  #[serde(rename = "pics", skip_serializing_if = "Vec::is_empty", default)]
+  pics: Vec<WeiboPic>,
+
+  #[serde(rename = "page_info", skip_serializing_if = "Option::is_none")]
+  page_info: Option<WeiboPageInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboUser {
+  #[serde(rename = "screen_name")]
+  screen_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboPic {
+  #[serde(rename = "url")]
+  url: String,
+
+  #[serde(rename = "large", skip_serializing_if = "Option::is_none")]
+  large: Option<WeiboPicLarge>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboPicLarge {
+  #[serde(rename = "url")]
+  url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboPageInfo {
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  kind: Option<String>,
+
+  #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+  title: Option<String>,
+
+  #[serde(rename = "media_info", skip_serializing_if = "Option::is_none")]
+  media_info: Option<WeiboMediaInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WeiboMediaInfo {
+  #[serde(rename = "stream_url_hd", skip_serializing_if = "Option::is_none")]
+  stream_url_hd: Option<String>,
+
+  #[serde(rename = "stream_url", skip_serializing_if = "Option::is_none")]
+  stream_url: Option<String>,
+}
+
+/// Converts the creation time of a post into a unix timestamp, weibo answers
+/// with `Sat Oct 18 10:00:00 +0800 2026` or with the relative forms of the
+/// mobile site such as `刚刚`, `5分钟前`, `2小时前`, `昨天 12:00`, `10-18` and
+/// `2025-10-18`.
+fn parse_created_at(text: &str) -> Option<u64> {
+  let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+  parse_created_at_from(text, now)
+}
+
+fn parse_created_at_from(text: &str, now: u64) -> Option<u64> {
+  let text = text.trim();
+  if let Some(time) = parse_absolute_created_at(text) {
+    return Some(time);
+  }
+  let ago = |suffix: &str, unit: u64| {
+    let n = text.strip_suffix(suffix)?.trim().parse::<u64>().ok()?;
+    now.checked_sub(n.checked_mul(unit)?)
+  };
+  // midnight of today in China Standard Time
+  const CST: u64 = 8 * 3600;
+  let today = (now + CST) / 86400 * 86400 - CST;
+  let clock = |text: &str| {
+    let (hour, minute) = text.trim().split_once(':')?;
+    Some(hour.parse::<u64>().ok()? * 3600 + minute.parse::<u64>().ok()? * 60)
+  };
+  match text {
+    "刚刚" => Some(now),
+    _ if text.ends_with("秒前") => ago("秒前", 1),
+    _ if text.ends_with("分钟前") => ago("分钟前", 60),
+    _ if text.ends_with("小时前") => ago("小时前", 3600),
+    _ if text.starts_with("今天") => Some(today + clock(text.strip_prefix("今天")?)?),
+    _ if text.starts_with("昨天") => Some(today - 86400 + clock(text.strip_prefix("昨天")?)?),
+    // `10-18` of the current year, `2025-10-18` of another one
+    _ if text.len() == 5 => {
+      let year = year_of_days((now + CST) / 86400);
+      parse_datetime(&format!("{year}-{text} 00:00:00"))
+    }
+    _ if text.len() == 10 => parse_datetime(&format!("{text} 00:00:00")),
+    _ => None,
+  }
+}
+
+/// Year of a count of days since the unix epoch, see
+/// http://howardhinnant.github.io/date_algorithms.html
+fn year_of_days(days: u64) -> u64 {
+  let z = days + 719468;
+  let (era, doe) = (z / 146097, z % 146097);
+  let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let month_from_march = (5 * doy + 2) / 153;
+  era * 400 + yoe + u64::from(month_from_march >= 10)
+}
+
+/// Converts `Sat Oct 18 10:00:00 +0800 2026` into a unix timestamp.
+fn parse_absolute_created_at(text: &str) -> Option<u64> {
+  let parts: Vec<&str> = text.split_whitespace().collect();
+  let [_, month, day, time, "+0800", year] = parts.as_slice() else {
+    return None;
+  };
+  const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+  ];
+  let month = MONTHS.iter().position(|m| m == month)? + 1;
+  parse_datetime(&format!("{year}-{month:02}-{day:0>2} {time}"))
+}
+
+impl From<WeiboPost> for SearchRes {
+  fn from(value: WeiboPost) -> Self {
+    let mut medias: Vec<MediaData> = value
+      .pics
+      .into_iter()
+      .map(|p| MediaData::new_image(p.large.map(|l| l.url).unwrap_or(p.url)))
+      .collect();
+    if let Some(info) = value.page_info
+      && info.kind.as_deref() == Some("video")
+      && let Some(media) = info.media_info
+      && let Some(url) = not_empty_str(media.stream_url_hd).or(not_empty_str(media.stream_url))
+    {
+      let video = MediaData::new_video(url);
+      medias.push(match not_empty_str(info.title) {
+        Some(title) => video.with_desc(title),
+        None => video,
+      });
+    }
+    Self {
+      title: strip_html(&value.text),
+      url: format!("https://m.weibo.cn/detail/{}", value.id),
+      time: value.created_at.as_deref().and_then(parse_created_at),
+      medias: if medias.is_empty() {
+        None
+      } else {
+        Some(medias)
+      },
+      desc: None,
+      author: value.user.map(|u| u.screen_name),
+      likes: value.attitudes_count,
+      comments: value.comments_count,
+      kind: None,
+      category: None,
+    }
+  }
+}
+
+impl From<WeiboSearchRes> for SearchesRes {
+  fn from(value: WeiboSearchRes) -> Self {
+    Self {
+      platform: PlatformType::Weibo,
+      ignored: Vec::new(),
+      next: value
+        .data
+        .cardlist_info
+        .and_then(|i| i.page)
+        .filter(|p| *p > 1)
+        .map(|p| PageParam::Other(p as u32 - 1)),
+      result: value
+        .data
+        .cards
+        .into_iter()
+        .flat_map(WeiboCard::into_posts)
+        .map(|p| p.into())
+        .collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_created_at_forms() {
+    let now = parse_datetime("2026-10-18 15:30:00").unwrap();
+    let at = |text: &str| parse_datetime(text).unwrap();
+    let parse = |text: &str| parse_created_at_from(text, now);
+
+    assert_eq!(
+      parse("Sat Oct 18 10:00:00 +0800 2026"),
+      Some(at("2026-10-18 10:00:00"))
+    );
+    assert_eq!(parse("刚刚"), Some(now));
+    assert_eq!(parse("30秒前"), Some(now - 30));
+    assert_eq!(parse("5分钟前"), Some(now - 300));
+    assert_eq!(parse("2小时前"), Some(now - 7200));
+    assert_eq!(parse("今天 08:05"), Some(at("2026-10-18 08:05:00")));
+    assert_eq!(parse("昨天 12:00"), Some(at("2026-10-17 12:00:00")));
+    assert_eq!(parse("10-01"), Some(at("2026-10-01 00:00:00")));
+    assert_eq!(parse("2025-12-31"), Some(at("2025-12-31 00:00:00")));
+  }
+
+  #[test]
+  fn parse_created_at_rejects_unknown() {
+    let now = parse_datetime("2026-01-01 00:30:00").unwrap();
+    assert_eq!(parse_created_at_from("", now), None);
+    assert_eq!(parse_created_at_from("很久以前", now), None);
+    assert_eq!(
+      parse_created_at_from("Sat Oct 18 10:00:00 +0000 2026", now),
+      None
+    );
+    // before 8:00 utc the day in China is already the next one
+    assert_eq!(
+      parse_created_at_from("01-01", now),
+      parse_datetime("2026-01-01 00:00:00")
+    );
+  }
+
+  #[test]
+  fn search_rejects_accounts_and_reports_shadowed_sort() {
+    let req = SearchReq::new("rust").with_kind(SearchType::Account);
+    assert!(matches!(
+      WeiboSearchReq::try_from(&req),
+      Err(TrendingError::InvalidParam { .. })
+    ));
+
+    let req = SearchReq::new("rust")
+      .with_kind(SearchType::Video)
+      .with_sort(SearchSort::Newest);
+    let query = WeiboSearchReq::try_from(&req).unwrap();
+    assert_eq!(query.container_id, "100103type=64&q=rust");
+    let res = SearchesRes {
+      platform: PlatformType::Weibo,
+      result: Vec::new(),
+      ignored: Vec::new(),
+      next: None,
+    };
+    let res = ignore_shadowed_sort(res, &req).filtered(&req);
+    assert_eq!(res.ignored, [SearchFilter::Sort]);
+
+    let req = SearchReq::new("rust").with_sort(SearchSort::Newest);
+    let res = SearchesRes {
+      platform: PlatformType::Weibo,
+      result: Vec::new(),
+      ignored: Vec::new(),
+      next: None,
+    };
+    assert!(
+      ignore_shadowed_sort(res, &req)
+        .filtered(&req)
+        .ignored
+        .is_empty()
+    );
+  }
+}
@@ -1,29 +1,84 @@
-use reqwest::Client as AsyncClient;
 #[cfg(feature = "blocking")]
 use reqwest::blocking::Client as BlockClient;
+use reqwest::{Client as AsyncClient, header::HeaderMap};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "blocking")]
 use crate::common::block_http_get;
 use crate::{
-  common::{EmptyType, PlatformType, TrendingRes, TrendingsRes, http_get, not_empty_str},
-  errors::Result,
+  common::{
+    EmptyType, PageParam, PlatformType, SearchFilter, SearchReq, SearchRes, SearchSort, SearchType,
+    SearchesRes, TrendingRes, TrendingsRes, http_get, not_empty_str, strip_html,
+  },
+  errors::{InvalidParamSnafu, Result, TrendingError},
 };
 
-pub const TRENDING_ENDPOINT: &'static str = "https://api.zhihu.com/topstory/hot-lists/total";
+pub const TRENDING_ENDPOINT: &str = "https://api.zhihu.com/topstory/hot-lists/total";
+pub const SEARCH_ENDPOINT: &str = "https://www.zhihu.com/api/v4/search_v3";
 
-pub async fn trending(client: &AsyncClient) -> Result<TrendingsRes> {
-  http_get::<EmptyType, EmptyType, ZhihuRes>(client, TRENDING_ENDPOINT, None, None, None)
+/// Filters of [`SearchReq`] honored by the search.
+pub const SEARCH_FILTERS: &[SearchFilter] = &[SearchFilter::Sort, SearchFilter::Size];
+
+/// Page size used when the search request has no size.
+const SEARCH_PAGE_SIZE: u32 = 20;
+
+pub async fn trending(client: &AsyncClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  http_get::<EmptyType, EmptyType, ZhihuRes>(client, TRENDING_ENDPOINT, headers, None, None)
+    .await
+    .map(|r| r.into())
+}
+
+/// Searches questions, answers and articles, zhihu may ask for a logged in
+/// cookie which can be set with the platform headers of the client.
+pub async fn search(
+  client: &AsyncClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  let (url, query) = search_target(req)?;
+  http_get::<ZhihuSearchReq, EmptyType, ZhihuSearchRes>(client, url, headers, query.as_ref(), None)
     .await
     .map(|r| r.into())
 }
 
 #[cfg(feature = "blocking")]
-pub fn block_trending(client: &BlockClient) -> Result<TrendingsRes> {
-  block_http_get::<EmptyType, EmptyType, ZhihuRes>(client, TRENDING_ENDPOINT, None, None, None)
+pub fn block_trending(client: &BlockClient, headers: Option<HeaderMap>) -> Result<TrendingsRes> {
+  block_http_get::<EmptyType, EmptyType, ZhihuRes>(client, TRENDING_ENDPOINT, headers, None, None)
     .map(|r| r.into())
 }
 
+#[cfg(feature = "blocking")]
+pub fn blocking_search(
+  client: &BlockClient,
+  headers: Option<HeaderMap>,
+  req: &SearchReq,
+) -> Result<SearchesRes> {
+  let (url, query) = search_target(req)?;
+  block_http_get::<ZhihuSearchReq, EmptyType, ZhihuSearchRes>(
+    client,
+    url,
+    headers,
+    query.as_ref(),
+    None,
+  )
+  .map(|r| r.into())
+}
+
+/// Zhihu hands out the url of the next page as cursor, which already carries
+/// the query, other pages are built from the request.
+fn search_target(req: &SearchReq) -> Result<(&str, Option<ZhihuSearchReq<'_>>)> {
+  match &req.page {
+    Some(PageParam::Cursor(next)) if next.starts_with(SEARCH_ENDPOINT) => Ok((next, None)),
+    Some(PageParam::Cursor(next)) => InvalidParamSnafu {
+      platform: PlatformType::Zhihu.to_str(),
+      param: "page",
+      value: next,
+    }
+    .fail(),
+    _ => Ok((SEARCH_ENDPOINT, Some(req.try_into()?))),
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct ZhihuRes {
   #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
@@ -62,6 +117,7 @@ impl From<ZhihuData> for TrendingRes {
         value.target.kind, value.target.id
       ),
       trend: not_empty_str(value.target.detail_text),
+      ..Default::default()
     }
   }
 }
@@ -74,3 +130,242 @@ impl From<ZhihuRes> for TrendingsRes {
     }
   }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuSearchReq<'a> {
+  #[serde(rename = "t")]
+  kind: &'a str,
+
+  #[serde(rename = "q")]
+  query: &'a str,
+
+  #[serde(rename = "correction")]
+  correction: u32,
+
+  #[serde(rename = "offset")]
+  offset: u32,
+
+  #[serde(rename = "limit")]
+  limit: u32,
+
+  #[serde(rename = "sort", skip_serializing_if = "Option::is_none")]
+  sort: Option<&'a str>,
+}
+
+impl<'a> TryFrom<&'a SearchReq> for ZhihuSearchReq<'a> {
+  type Error = TrendingError;
+
+  fn try_from(value: &'a SearchReq) -> Result<Self> {
+    let limit = value.size.unwrap_or(SEARCH_PAGE_SIZE);
+    Ok(Self {
+      kind: "general",
+      query: value.keyword.as_ref(),
+      correction: 1,
+      offset: value
+        .page
+        .as_ref()
+        .map_or(Ok(0), |p| p.offset(PlatformType::Zhihu, limit))?,
+      limit,
+      sort: value.sort.and_then(|s| match s {
+        SearchSort::Relevance => None,
+        SearchSort::Newest => Some("created_time"),
+        SearchSort::Hottest => Some("upvoted_count"),
+      }),
+    })
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuSearchRes {
+  #[serde(rename = "data", skip_serializing_if = "Vec::is_empty", default)]
+  data: Vec<ZhihuSearchData>,
+
+  #[serde(rename = "paging", skip_serializing_if = "Option::is_none")]
+  paging: Option<ZhihuPaging>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuPaging {
+  #[serde(rename = "is_end", default)]
+  is_end: bool,
+
+  /// Url of the next page.
+  #[serde(rename = "next", skip_serializing_if = "Option::is_none")]
+  next: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuSearchData {
+  /// `search_result` for contents, the others are cards such as related searches.
+  #[serde(rename = "type")]
+  kind: String,
+
+  #[serde(rename = "object", skip_serializing_if = "Option::is_none")]
+  object: Option<ZhihuSearchObject>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuSearchObject {
+  /// One of `question`, `answer`, `article` and `zvideo`.
+  #[serde(rename = "type")]
+  kind: String,
+
+  #[serde(rename = "id")]
+  id: String,
+
+  #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+  title: Option<String>,
+
+  #[serde(rename = "excerpt", skip_serializing_if = "Option::is_none")]
+  excerpt: Option<String>,
+
+  #[serde(rename = "voteup_count", skip_serializing_if = "Option::is_none")]
+  voteup_count: Option<u64>,
+
+  #[serde(rename = "comment_count", skip_serializing_if = "Option::is_none")]
+  comment_count: Option<u64>,
+
+  #[serde(rename = "created_time", skip_serializing_if = "Option::is_none")]
+  created_time: Option<u64>,
+
+  #[serde(rename = "author", skip_serializing_if = "Option::is_none")]
+  author: Option<ZhihuAuthor>,
+
+  #[serde(rename = "question", skip_serializing_if = "Option::is_none")]
+  question: Option<ZhihuQuestion>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuAuthor {
+  #[serde(rename = "name")]
+  name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ZhihuQuestion {
+  #[serde(rename = "id")]
+  id: String,
+
+  #[serde(rename = "name")]
+  name: String,
+}
+
+impl ZhihuSearchObject {
+  fn into_search(self) -> Option<SearchRes> {
+    let (title, url, kind) = match (self.kind.as_str(), self.question) {
+      ("answer", Some(question)) => (
+        question.name,
+        format!(
+          "https://www.zhihu.com/question/{}/answer/{}",
+          question.id, self.id
+        ),
+        SearchType::News,
+      ),
+      ("article", _) => (
+        self.title?,
+        format!("https://zhuanlan.zhihu.com/p/{}", self.id),
+        SearchType::News,
+      ),
+      ("question", _) => (
+        self.title?,
+        format!("https://www.zhihu.com/question/{}", self.id),
+        SearchType::News,
+      ),
+      ("zvideo", _) => (
+        self.title?,
+        format!("https://www.zhihu.com/zvideo/{}", self.id),
+        SearchType::Video,
+      ),
+      _ => return None,
+    };
+    Some(SearchRes {
+      title: strip_html(&title),
+      url,
+      time: self.created_time,
+      medias: None,
+      desc: not_empty_str(self.excerpt.map(|e| strip_html(&e))),
+      author: self.author.map(|a| a.name),
+      likes: self.voteup_count,
+      comments: self.comment_count,
+      kind: Some(kind),
+      category: Some(self.kind),
+    })
+  }
+}
+
+impl From<ZhihuSearchRes> for SearchesRes {
+  fn from(value: ZhihuSearchRes) -> Self {
+    Self {
+      platform: PlatformType::Zhihu,
+      ignored: Vec::new(),
+      next: value
+        .paging
+        .filter(|p| !p.is_end)
+        .and_then(|p| not_empty_str(p.next))
+        .map(PageParam::Cursor),
+      result: value
+        .data
+        .into_iter()
+        .filter(|d| d.kind == "search_result")
+        .filter_map(|d| d.object?.into_search())
+        .collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn search_target_follows_only_zhihu_cursors() {
+    let next = format!("{SEARCH_ENDPOINT}?q=rust&offset=20");
+    let req = SearchReq::new("rust").with_page(PageParam::Cursor(next.clone()));
+    let (url, query) = search_target(&req).unwrap();
+    assert_eq!(url, next);
+    assert!(query.is_none());
+
+    let req = SearchReq::new("rust").with_page(PageParam::Cursor("https://example.com".into()));
+    assert!(matches!(
+      search_target(&req),
+      Err(TrendingError::InvalidParam { .. })
+    ));
+
+    let req = SearchReq::new("rust").with_page(PageParam::Other(2));
+    let (url, query) = search_target(&req).unwrap();
+    assert_eq!(url, SEARCH_ENDPOINT);
+    assert_eq!(query.unwrap().offset, 40);
+  }
+
+  #[test]
+  fn search_object_reports_category() {
+    let objects: Vec<ZhihuSearchObject> = serde_json::from_str(
+      r#"[
+        {"type": "answer", "id": "2", "question": {"id": "1", "name": "<em>Rust</em> 好用吗"}},
+        {"type": "article", "id": "3", "title": "Rust 入门"},
+        {"type": "question", "id": "4", "title": "如何学习 Rust"},
+        {"type": "zvideo", "id": "5", "title": "Rust 视频"},
+        {"type": "topic", "id": "6", "title": "Rust"}
+      ]"#,
+    )
+    .unwrap();
+    let res: Vec<SearchRes> = objects
+      .into_iter()
+      .filter_map(ZhihuSearchObject::into_search)
+      .collect();
+    let categories: Vec<_> = res.iter().map(|r| r.category.as_deref()).collect();
+    assert_eq!(
+      categories,
+      [
+        Some("answer"),
+        Some("article"),
+        Some("question"),
+        Some("zvideo")
+      ]
+    );
+    assert_eq!(res[0].title, "Rust 好用吗");
+    assert_eq!(res[0].url, "https://www.zhihu.com/question/1/answer/2");
+    assert_eq!(res[0].kind, Some(SearchType::News));
+    assert_eq!(res[3].kind, Some(SearchType::Video));
+  }
+}
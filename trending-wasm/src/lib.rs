@@ -0,0 +1,361 @@
+use std::{collections::HashMap, str::FromStr, time::Duration};
+
+use js_sys::{Error as JsError, Reflect};
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
+use trending::{
+  client::{AsyncClient, ClientOptions as RClientOptions},
+  common::{PageParam, PlatformType, SearchReq as RSearchReq, SearchSort, SearchType, TrendingReq},
+  errors::{ReqwestHeaderNameSnafu, ReqwestHeaderValueSnafu, TrendingError},
+};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+export type PlatformType =
+  | "zhihu"
+  | "weibo"
+  | "toutiao"
+  | "tencent"
+  | "tieba"
+  | "netease"
+  | "hupu"
+  | "bilibili"
+  | "douyin"
+  | "baidu"
+  | "douban"
+  | "kuaishou"
+  | "36kr"
+  | "huxiu"
+  | "ithome"
+  | "sspai"
+  | "juejin"
+  | "v2ex"
+  | "csdn"
+  | "hackernews"
+  | "reddit"
+  | "github"
+  | "thepaper"
+  | "ifeng";
+
+export interface ClientOptions {
+  headers?: Record<string, string>;
+  platformHeaders?: Partial<Record<PlatformType, Record<string, string>>>;
+  retries?: number;
+  /** Milliseconds before a request is aborted. */
+  timeoutMs?: number;
+  /** Milliseconds trending results are reused for. */
+  cacheTtlMs?: number;
+}
+
+export interface TrendingReq {
+  board?: string;
+  params?: Record<string, string>;
+}
+
+export type SearchType = "news" | "video" | "account";
+
+export type SearchSort = "relevance" | "newest" | "hottest";
+
+export type SearchFilter = "since" | "until" | "sort" | "kind" | "size";
+
+export type PageParam =
+  | "First"
+  | { Other: number }
+  | { Offset: number }
+  | { Cursor: string };
+
+export interface SearchReq {
+  keyword: string;
+  page?: number | "first" | PageParam;
+  size?: number;
+  kind?: SearchType;
+  since?: number;
+  until?: number;
+  sort?: SearchSort;
+}
+
+export interface TrendingRes {
+  title: string;
+  url: string;
+  trend: string | null;
+  medias?: MediaData[];
+  label?: string;
+  desc?: string;
+  author?: string;
+  time?: number;
+  reads?: number;
+  comments?: number;
+  rank?: number;
+  language?: string;
+}
+
+export interface TrendingsRes {
+  platform: PlatformType;
+  /** Left out when the list is empty. */
+  trendings?: TrendingRes[];
+}
+
+export interface MediaData {
+  url: string;
+  kind: "video" | "audio" | "image" | (string & {});
+  desc?: string;
+}
+
+export interface SearchRes {
+  title: string;
+  url: string;
+  time: number | null;
+  medias?: MediaData[];
+  desc?: string;
+  author?: string;
+  likes?: number;
+  comments?: number;
+  kind?: SearchType;
+  category?: string;
+}
+
+export interface SearchesRes {
+  platform: PlatformType;
+  /** Left out when there are no results. */
+  searches?: SearchRes[];
+  ignored?: SearchFilter[];
+  next?: PageParam;
+}
+"#;
+
+#[derive(Deserialize, Debug)]
+struct ClientOptions {
+  #[serde(rename = "headers", default)]
+  headers: HashMap<String, String>,
+
+  #[serde(rename = "platformHeaders", default)]
+  platform_headers: HashMap<String, HashMap<String, String>>,
+
+  #[serde(rename = "retries")]
+  retries: Option<u32>,
+
+  #[serde(rename = "timeoutMs")]
+  timeout_ms: Option<u64>,
+
+  #[serde(rename = "cacheTtlMs")]
+  cache_ttl_ms: Option<u64>,
+}
+
+impl TryFrom<ClientOptions> for RClientOptions {
+  type Error = TrendingError;
+
+  fn try_from(value: ClientOptions) -> Result<Self, Self::Error> {
+    let mut options = RClientOptions::new();
+    for (name, value) in value.headers {
+      let (name, value) = parse_header(&name, &value)?;
+      options = options.with_header(name, value);
+    }
+    for (platform, headers) in value.platform_headers {
+      let platform = PlatformType::from(platform.as_str());
+      for (name, value) in headers {
+        let (name, value) = parse_header(&name, &value)?;
+        options = options.with_platform_header(platform.clone(), name, value);
+      }
+    }
+    if let Some(retries) = value.retries {
+      options = options.with_retries(retries);
+    }
+    if let Some(timeout) = value.timeout_ms {
+      options = options.with_timeout(Duration::from_millis(timeout));
+    }
+    if let Some(ttl) = value.cache_ttl_ms {
+      options = options.with_cache_ttl(Duration::from_millis(ttl));
+    }
+    Ok(options)
+  }
+}
+
+fn parse_header(name: &str, value: &str) -> Result<(HeaderName, HeaderValue), TrendingError> {
+  let name = HeaderName::from_str(name).context(ReqwestHeaderNameSnafu { name })?;
+  let value = HeaderValue::from_str(value).context(ReqwestHeaderValueSnafu { value })?;
+  Ok((name, value))
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum PageArg {
+  Other(u32),
+  First(FirstPage),
+  /// The `next` of a previous result.
+  Param(PageParam),
+}
+
+#[derive(Deserialize, Debug)]
+enum FirstPage {
+  #[serde(rename = "first")]
+  First,
+}
+
+#[derive(Deserialize, Debug)]
+struct SearchReq {
+  #[serde(rename = "keyword")]
+  keyword: String,
+
+  #[serde(rename = "page")]
+  page: Option<PageArg>,
+
+  #[serde(rename = "size")]
+  size: Option<u32>,
+
+  #[serde(rename = "kind")]
+  kind: Option<SearchType>,
+
+  #[serde(rename = "since")]
+  since: Option<u64>,
+
+  #[serde(rename = "until")]
+  until: Option<u64>,
+
+  #[serde(rename = "sort")]
+  sort: Option<SearchSort>,
+}
+
+impl From<SearchReq> for RSearchReq {
+  fn from(value: SearchReq) -> Self {
+    Self {
+      keyword: value.keyword,
+      page: value.page.map(|p| match p {
+        PageArg::Other(page) => PageParam::Other(page),
+        PageArg::First(_) => PageParam::First,
+        PageArg::Param(page) => page,
+      }),
+      size: value.size,
+      kind: value.kind,
+      since: value.since,
+      until: value.until,
+      sort: value.sort,
+    }
+  }
+}
+
+#[wasm_bindgen]
+pub struct Client {
+  client: AsyncClient,
+}
+
+#[wasm_bindgen]
+impl Client {
+  #[wasm_bindgen(constructor)]
+  pub fn new(
+    #[wasm_bindgen(unchecked_param_type = "ClientOptions")] options: Option<JsValue>,
+  ) -> Result<Client, JsValue> {
+    let client = if let Some(options) = options {
+      let options: ClientOptions = serde_wasm_bindgen::from_value(options)?;
+      let options = RClientOptions::try_from(options).map_err(|e| to_js_error(e, None))?;
+      AsyncClient::new_with_options(options).map_err(|e| to_js_error(e, None))?
+    } else {
+      AsyncClient::new()
+    };
+    Ok(Client { client })
+  }
+
+  #[wasm_bindgen(unchecked_return_type = "TrendingsRes")]
+  pub async fn trending(
+    &self,
+    #[wasm_bindgen(unchecked_param_type = "PlatformType")] platform: String,
+    #[wasm_bindgen(unchecked_param_type = "TrendingReq | undefined")] req: Option<JsValue>,
+  ) -> Result<JsValue, JsValue> {
+    let platform = PlatformType::from(platform.as_str());
+    let req: TrendingReq = match req {
+      Some(req) => serde_wasm_bindgen::from_value(req)?,
+      None => TrendingReq::default(),
+    };
+    let res = self
+      .client
+      .trending_with_req(&platform, &req)
+      .await
+      .map_err(|e| to_js_error(e, Some(&platform)))?;
+    to_js_value(&res)
+  }
+
+  #[wasm_bindgen(unchecked_return_type = "SearchesRes")]
+  pub async fn search(
+    &self,
+    #[wasm_bindgen(unchecked_param_type = "PlatformType")] platform: String,
+    #[wasm_bindgen(unchecked_param_type = "SearchReq")] req: JsValue,
+  ) -> Result<JsValue, JsValue> {
+    let platform = PlatformType::from(platform.as_str());
+    let req: SearchReq = serde_wasm_bindgen::from_value(req)?;
+    let res = self
+      .client
+      .search(&platform, &req.into())
+      .await
+      .map_err(|e| to_js_error(e, Some(&platform)))?;
+    to_js_value(&res)
+  }
+}
+
+fn to_js_value<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+  let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+  Ok(value.serialize(&serializer)?)
+}
+
+fn to_js_error(err: TrendingError, platform: Option<&PlatformType>) -> JsValue {
+  let error = JsError::new(&err.detailed_message());
+  let props = [
+    ("platform", platform.map(|p| JsValue::from_str(p.to_str()))),
+    ("endpoint", err.endpoint().map(JsValue::from_str)),
+    ("status", err.status().map(JsValue::from)),
+  ];
+  for (key, value) in props {
+    let _ = Reflect::set(&error, &key.into(), &value.unwrap_or(JsValue::NULL));
+  }
+  error.into()
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+  use super::*;
+  use trending::common::{SearchesRes, TrendingsRes};
+  use wasm_bindgen_test::wasm_bindgen_test;
+
+  fn js_object(json: &str) -> JsValue {
+    js_sys::JSON::parse(json).unwrap()
+  }
+
+  #[wasm_bindgen_test]
+  fn client_options_from_js() {
+    let options: ClientOptions = serde_wasm_bindgen::from_value(js_object(
+      r#"{"headers": {"user-agent": "trending"}, "platformHeaders": {"douyin": {"cookie": "a=b"}},
+        "retries": 2, "timeoutMs": 5000, "cacheTtlMs": 60000}"#,
+    ))
+    .unwrap();
+    let options = RClientOptions::try_from(options).unwrap();
+    assert_eq!(options.headers["user-agent"], "trending");
+    assert_eq!(
+      options.platform_headers[&PlatformType::Douyin]["cookie"],
+      "a=b"
+    );
+    assert_eq!(options.retries, Some(2));
+    assert_eq!(options.timeout, Some(Duration::from_millis(5000)));
+    assert_eq!(options.cache_ttl, Some(Duration::from_millis(60000)));
+    assert!(Client::new(Some(js_object(r#"{"timeoutMs": 5000}"#))).is_ok());
+  }
+
+  #[wasm_bindgen_test]
+  fn empty_results_leave_out_the_list() {
+    let res: TrendingsRes =
+      serde_wasm_bindgen::from_value(js_object(r#"{"platform": "zhihu"}"#)).unwrap();
+    let value = to_js_value(&res).unwrap();
+    assert_eq!(
+      Reflect::get(&value, &"platform".into()).unwrap(),
+      JsValue::from_str("zhihu")
+    );
+    assert!(!Reflect::has(&value, &"trendings".into()).unwrap());
+    let back: TrendingsRes = serde_wasm_bindgen::from_value(value).unwrap();
+    assert!(back.result.is_empty());
+
+    let res: SearchesRes =
+      serde_wasm_bindgen::from_value(js_object(r#"{"platform": "tencent"}"#)).unwrap();
+    let value = to_js_value(&res).unwrap();
+    assert!(!Reflect::has(&value, &"searches".into()).unwrap());
+    assert!(!Reflect::has(&value, &"next".into()).unwrap());
+  }
+}